@@ -11,8 +11,8 @@ pub enum ErrorCode {
     #[msg("Mint account passed != mint account in config")]
     MintDoesNotMatch,
 
-    #[msg("Minted mutations require a Candy Machine ID to be present")]
-    CandyMachineMissing,
+    #[msg("Minted rewards require the transmuter authority to be the mint authority")]
+    MintAuthorityMissing,
 
     #[msg("Arithmetic error (likely under/overflow)")]
     ArithmeticError,
@@ -52,4 +52,123 @@ pub enum ErrorCode {
 
     #[msg("Account discriminator mismatch")]
     AccountDiscriminatorMismatch,
+
+    #[msg("Token prices require a price mint to be set")]
+    PriceMintMissing,
+
+    #[msg("Account has already been migrated to the latest layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Revenue splits must have non-zero shares adding up to 10000 bps (max 4)")]
+    InvalidRevenueSplits,
+
+    #[msg("Dutch auctions need start_ts < end_ts and a start price >= the base price")]
+    InvalidPricingMode, //20 = 0x1784
+
+    #[msg("The window for reversing this mutation has closed")]
+    ReversalWindowClosed,
+
+    #[msg("This execution has already been reversed the maximum number of times")]
+    MaxReversalsReached,
+
+    #[msg("Mutation start_ts must be before end_ts")]
+    InvalidAvailabilityWindow,
+
+    #[msg("This mutation isn't available yet (before start_ts)")]
+    MutationNotYetAvailable,
+
+    #[msg("This mutation is no longer available (after end_ts)")]
+    MutationNoLongerAvailable, //25 = 0x1789
+
+    #[msg("Mutation has executions pending completion")]
+    MutationHasPendingExecutions,
+
+    #[msg("This taker has reached the max number of executions for this mutation")]
+    TakerExecutionLimitReached,
+
+    #[msg("This taker has to wait for the cooldown to pass before executing again")]
+    TakerCooldownActive,
+
+    #[msg("This taker isn't allowed to execute this mutation (invalid allow/denylist proof)")]
+    TakerNotAllowed,
+
+    #[msg("Transmuter already has the max number of banks")]
+    NoMoreBankSlots, //30 = 0x178e
+
+    #[msg(
+        "Loot table weights must only be set on maker slots in use, on a short, non-reversible mutation"
+    )]
+    InvalidLootTable,
+
+    #[msg("Loot roll can only be revealed in a later slot than the one it was committed in")]
+    LootNotYetRevealable,
+
+    #[msg("Not all gems in the vault were burned, pass accounts for every gem box")]
+    VaultNotFullyBurned,
+
+    #[msg("Metadata template name / uri are too long (or uri is empty)")]
+    InvalidMetadataTemplate,
+
+    #[msg("Invalid nft reward config or template (too long, reversible, or list already set)")]
+    InvalidNftReward, //35 = 0x1793
+
+    #[msg("Not enough nft templates left for this execution")]
+    NftTemplatesExhausted,
+
+    #[msg("Price mint can't be changed while executions are pending")]
+    ImmutableMutationConfig,
+
+    #[msg("Transmuter or mutation is paused, no new executions can be started")]
+    MutationPaused,
+
+    #[msg("Signer is neither the owner nor holds the role required for this instruction")]
+    MissingRole,
+
+    #[msg("Signer isn't the transmuter's pending owner")]
+    NotPendingOwner, //40 = 0x1798
+
+    #[msg("Transmuter still has mutations, destroy them first")]
+    TransmuterHasMutations,
+
+    #[msg("Bank a can't be skipped, and the same bank can't be used twice")]
+    InvalidBankSetup,
+
+    #[msg("Too many fee exempt wallets, or execution fee above 100%")]
+    InvalidProtocolConfig,
+
+    #[msg("Only the program's upgrade authority can init the protocol config")]
+    NotUpgradeAuthority,
+
+    #[msg("Only pending executions can be cancelled")]
+    ExecutionNotPending, //45 = 0x179d
+
+    #[msg("Executions with a loot or nft roll can't be cancelled")]
+    CancellationNotAllowed,
+
+    #[msg("Cancellation penalty can't be more than 100%")]
+    InvalidCancellationPenalty,
+
+    #[msg("Pending executions have to be completed or cancelled before the receipt can be closed")]
+    ExecutionStillPending,
+
+    #[msg("Gem accounts or bumps missing for a vault that's burned / has its metadata updated")]
+    MissingVaultGems,
+
+    #[msg("Pending executions can only be cancelled before they're due")]
+    CancellationWindowClosed, //50 = 0x17a2
+
+    #[msg("Mutation has to be paused or ended, and the execution overdue, to force cancel it")]
+    ForceCancellationNotAllowed,
+
+    #[msg("Only the owner can set revenue splits or have maker tokens minted")]
+    OwnerOnlyMutationConfig,
+
+    #[msg("Role was granted by a previous owner of the transmuter")]
+    RoleRevoked,
+
+    #[msg("Vaults that get burned or have their metadata updated must hold exactly the required amount")]
+    ExcessVaultGems,
+
+    #[msg("Receipt still keeps the taker's vaults locked, it has to be reversed first")]
+    ReceiptLocksVaults, //55 = 0x17a7
 }
@@ -0,0 +1,32 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptTransmuterOwner<'info> {
+    // transmuter
+    #[account(mut)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    pub new_owner: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for AcceptTransmuterOwner<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.transmuter.pending_owner == Some(self.new_owner.key()),
+            NotPendingOwner
+        );
+        Ok(())
+    }
+}
+
+/// second half of the ownership transfer started in update_transmuter
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<AcceptTransmuterOwner>) -> Result<()> {
+    let transmuter = &mut ctx.accounts.transmuter;
+
+    transmuter.owner = ctx.accounts.new_owner.key();
+    transmuter.pending_owner = None;
+    // roles were handed out by the previous owner, the new one has to re-grant them
+    transmuter.role_epoch.try_add_assign(1)?;
+
+    Ok(())
+}
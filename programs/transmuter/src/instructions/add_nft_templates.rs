@@ -0,0 +1,37 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AddNftTemplates<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+
+    #[account(mut, has_one = mutation)]
+    pub nft_template_list: Box<Account<'info, NftTemplateList>>,
+}
+
+impl<'info> Validate<'info> for AddNftTemplates<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// appends to the list - can be called in batches, including after rewards started being minted
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<AddNftTemplates>, templates: Vec<NftTemplate>) -> Result<()> {
+    let nft_template_list = &mut ctx.accounts.nft_template_list;
+    let info = nft_template_list.to_account_info();
+    let mut data = info.try_borrow_mut_data()?;
+
+    nft_template_list.try_append(&mut data, &templates)
+}
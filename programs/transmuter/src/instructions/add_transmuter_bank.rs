@@ -0,0 +1,50 @@
+use crate::*;
+use gem_bank::{self, cpi::accounts::InitBank, program::GemBank};
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8)]
+pub struct AddTransmuterBank<'info> {
+    // transmuter
+    #[account(mut, has_one = authority, has_one = owner)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    pub owner: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
+    pub authority: AccountInfo<'info>,
+
+    // cpi
+    #[account(mut)]
+    pub bank: Signer<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    // misc
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddTransmuterBank<'info> {
+    fn init_bank_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitBank<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            InitBank {
+                bank: self.bank.to_account_info(),
+                bank_manager: self.authority.clone(),
+                payer: self.payer.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+}
+
+/// banks a/b/c are created together with the transmuter, this adds the extra ones one by one
+pub fn handler(ctx: Context<AddTransmuterBank>) -> Result<()> {
+    let bank = ctx.accounts.bank.key();
+    ctx.accounts.transmuter.try_add_extra_bank(bank)?;
+
+    gem_bank::cpi::init_bank(
+        ctx.accounts
+            .init_bank_ctx()
+            .with_signer(&[&ctx.accounts.transmuter.get_seeds()]),
+    )
+}
@@ -7,10 +7,13 @@ use gem_bank::{
 #[instruction(bump_auth: u8)]
 pub struct AddRaritiesToBank<'info> {
     // transmuter
-    #[account(has_one = authority, has_one = owner)]
+    #[account(has_one = authority)]
     pub transmuter: Box<Account<'info, Transmuter>>,
+    /// owner, or a member with the curator role
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
     /// CHECK:
     #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
     pub authority: AccountInfo<'info>,
@@ -34,13 +37,25 @@ impl<'info> AddRaritiesToBank<'info> {
             RecordRarityPoints {
                 bank: self.bank.clone(),
                 bank_manager: self.authority.clone(),
-                payer: self.owner.to_account_info(),
+                payer: self.admin.to_account_info(),
                 system_program: self.system_program.to_account_info(),
             },
         )
     }
 }
 
+impl<'info> Validate<'info> for AddRaritiesToBank<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::Curator,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, AddRaritiesToBank<'info>>,
     rarity_configs: Vec<RarityConfig>,
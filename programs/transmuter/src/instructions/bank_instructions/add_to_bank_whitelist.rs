@@ -5,10 +5,13 @@ use gem_bank::{self, cpi::accounts::AddToWhitelist, program::GemBank, state::Ban
 #[instruction(bump_auth: u8)]
 pub struct AddToBankWhitelist<'info> {
     // transmuter
-    #[account(has_one = authority, has_one = owner)]
+    #[account(has_one = authority)]
     pub transmuter: Box<Account<'info, Transmuter>>,
+    /// owner, or a member with the curator role
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
     /// CHECK:
     #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
     pub authority: AccountInfo<'info>,
@@ -36,12 +39,24 @@ impl<'info> AddToBankWhitelist<'info> {
                 address_to_whitelist: self.address_to_whitelist.clone(),
                 whitelist_proof: self.whitelist_proof.clone(),
                 system_program: self.system_program.to_account_info(),
-                payer: self.owner.to_account_info(),
+                payer: self.admin.to_account_info(),
             },
         )
     }
 }
 
+impl<'info> Validate<'info> for AddToBankWhitelist<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::Curator,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
 pub fn handler(ctx: Context<AddToBankWhitelist>, whitelist_type: u8) -> Result<()> {
     gem_bank::cpi::add_to_whitelist(
         ctx.accounts
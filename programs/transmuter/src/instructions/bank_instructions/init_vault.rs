@@ -4,7 +4,7 @@ use arrayref::array_ref;
 use gem_bank::{self, cpi::accounts::InitVault, program::GemBank};
 
 #[derive(Accounts)]
-#[instruction(bump_creator: u8)]
+#[instruction(bump_creator: u8, nonce: u32)]
 pub struct InitTakerVault<'info> {
     // mutation
     pub transmuter: Box<Account<'info, Transmuter>>,
@@ -23,6 +23,7 @@ pub struct InitTakerVault<'info> {
             b"creator".as_ref(),
             mutation.key().as_ref(),
             taker.key().as_ref(),
+            nonce_seed(nonce).as_ref(),
         ],
         bump = bump_creator)]
     pub creator: AccountInfo<'info>,
@@ -32,12 +33,14 @@ pub struct InitTakerVault<'info> {
     #[account(mut)]
     pub taker: Signer<'info>,
     // has to be init_if_needed since we call this 3 times for 3 vaults
-    // same mutation + same taker -> deterministically same creator
+    // same mutation + same taker + same nonce -> deterministically same creator
     // same bank + same creator -> deterministically same vault
+    // (a new nonce gives a new receipt & new vaults, to run the mutation again)
     #[account(init_if_needed, seeds = [
             b"receipt".as_ref(),
             mutation.key().as_ref(),
-            taker.key().as_ref()
+            taker.key().as_ref(),
+            nonce_seed(nonce).as_ref(),
         ],
         bump,
         payer = taker,
@@ -61,7 +64,7 @@ impl<'info> InitTakerVault<'info> {
     }
 }
 
-pub fn handler(ctx: Context<InitTakerVault>, bump_creator: u8) -> Result<()> {
+pub fn handler(ctx: Context<InitTakerVault>, bump_creator: u8, nonce: u32) -> Result<()> {
     // fix missing discriminator check
     {
         let acct = ctx.accounts.execution_receipt.to_account_info();
@@ -73,6 +76,7 @@ pub fn handler(ctx: Context<InitTakerVault>, bump_creator: u8) -> Result<()> {
     }
 
     let transmuter = &ctx.accounts.transmuter;
+    ctx.accounts.mutation.assert_not_paused(transmuter)?;
     let receipt = &mut ctx.accounts.execution_receipt;
     let bank = ctx.accounts.bank.key();
     let vault = ctx.accounts.vault.key();
@@ -89,6 +93,8 @@ pub fn handler(ctx: Context<InitTakerVault>, bump_creator: u8) -> Result<()> {
         receipt.vault_b = Some(vault);
     } else if bank == transmuter.bank_c {
         receipt.vault_c = Some(vault);
+    } else if let Some(i) = transmuter.extra_bank_index(bank) {
+        receipt.extra_vaults[i] = Some(vault);
     } else {
         return Err(error!(ErrorCode::NoneOfTheBanksMatch));
     }
@@ -98,12 +104,14 @@ pub fn handler(ctx: Context<InitTakerVault>, bump_creator: u8) -> Result<()> {
     receipt.transmuter = ctx.accounts.transmuter.key();
     receipt.mutation = ctx.accounts.mutation.key();
     receipt.taker = ctx.accounts.taker.key();
+    receipt.nonce = nonce;
 
     gem_bank::cpi::init_vault(
         ctx.accounts.init_vault_ctx().with_signer(&[&[
             b"creator".as_ref(),
             ctx.accounts.mutation.key().as_ref(),
             ctx.accounts.taker.key().as_ref(),
+            nonce_seed(nonce).as_ref(),
             &[bump_creator],
         ]]),
         ctx.accounts.taker.key(),
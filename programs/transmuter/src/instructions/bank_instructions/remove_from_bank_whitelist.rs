@@ -12,8 +12,13 @@ pub struct RemoveFromBankWhitelist<'info> {
     // transmuter
     #[account(has_one = authority, has_one = owner)]
     pub transmuter: Box<Account<'info, Transmuter>>,
+    /// CHECK: receives the whitelist proof's rent
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: AccountInfo<'info>,
+    /// owner, or a member with the curator role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
     /// CHECK:
     #[account(mut, seeds = [transmuter.key().as_ref()], bump = bump_auth)]
     pub authority: AccountInfo<'info>,
@@ -39,12 +44,24 @@ impl<'info> RemoveFromBankWhitelist<'info> {
                 bank_manager: self.authority.clone(),
                 address_to_remove: self.address_to_remove.clone(),
                 whitelist_proof: self.whitelist_proof.to_account_info(),
-                funds_receiver: self.owner.to_account_info(),
+                funds_receiver: self.owner.clone(),
             },
         )
     }
 }
 
+impl<'info> Validate<'info> for RemoveFromBankWhitelist<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::Curator,
+        )
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
 pub fn handler(ctx: Context<RemoveFromBankWhitelist>, bump_wl: u8) -> Result<()> {
     gem_bank::cpi::remove_from_whitelist(
         ctx.accounts
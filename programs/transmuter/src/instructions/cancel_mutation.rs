@@ -0,0 +1,48 @@
+//! Lets a taker back out of a pending execution before it's due:
+//! 1) Vaults are unlocked, so the taker can withdraw again
+//! 2) The use is put back & the execution no longer counts towards the taker's limit
+//! 3) The payment held in escrow while pending is refunded, minus the mutation's cancellation penalty,
+//!    which goes to the maker (split & charged the protocol fee like any other payment)
+//! 4) The receipt goes back to NotStarted
+//! Executions with a roll can't be cancelled, or takers could back out of bad rolls.
+
+use crate::*;
+
+/// revenue split recipients are passed as remaining accounts, after the slots
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteMutation<'info>>,
+) -> Result<()> {
+    let config = ctx.accounts.mutation.config;
+    require!(config.can_cancel(), CancellationNotAllowed);
+
+    let (slots, split_accounts) = ctx.accounts.load_slots(ctx.remaining_accounts)?;
+
+    let execution_receipt = &mut ctx.accounts.execution_receipt;
+    require!(execution_receipt.is_pending(), ExecutionNotPending);
+    require!(
+        now_ts()? < execution_receipt.mutation_complete_ts,
+        CancellationWindowClosed
+    );
+    let (lamports, token_amount) = execution_receipt.release_payment();
+    execution_receipt.record_cancellation();
+
+    // --------------------------------------- uses & refund
+
+    ctx.accounts.taker_record.record_reversal();
+
+    let mutation = &mut ctx.accounts.mutation;
+    mutation.increment_uses()?;
+    mutation.finish_pending_execution()?;
+
+    ctx.accounts.refund_escrowed_payment(
+        lamports,
+        token_amount,
+        config.cancellation_penalty_bps as u64,
+        split_accounts,
+    )?;
+
+    // --------------------------------------- unlock taker vaults
+
+    ctx.accounts.set_taker_vaults_lock(&slots, false)
+}
@@ -0,0 +1,18 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelTransmuterOwner<'info> {
+    // transmuter
+    #[account(mut, has_one = owner)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    pub owner: Signer<'info>,
+}
+
+/// drops a proposed ownership transfer, eg if the wrong key was proposed
+pub fn handler(ctx: Context<CancelTransmuterOwner>) -> Result<()> {
+    let transmuter = &mut ctx.accounts.transmuter;
+
+    transmuter.pending_owner = None;
+
+    Ok(())
+}
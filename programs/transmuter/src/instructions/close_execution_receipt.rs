@@ -0,0 +1,53 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseExecutionReceipt<'info> {
+    /// CHECK: deserialized in validate, closed manually
+    #[account(mut)]
+    pub execution_receipt: AccountInfo<'info>,
+    /// CHECK: might already be destroyed
+    pub mutation: AccountInfo<'info>,
+    /// CHECK: checked against the receipt, gets the rent back
+    #[account(mut)]
+    pub taker: AccountInfo<'info>,
+    /// taker, or anyone once the mutation is destroyed
+    pub closer: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for CloseExecutionReceipt<'info> {
+    fn validate(&self) -> Result<()> {
+        let receipt: Account<ExecutionReceipt> = Account::try_from(&self.execution_receipt)?;
+        assert_keys_eq!(receipt.mutation, self.mutation.key(), "mutation");
+        assert_keys_eq!(receipt.taker, self.taker.key(), "taker");
+
+        // nothing can be done with the receipt once its mutation is gone, so anyone can clean it up
+        let mutation_destroyed = self.mutation.owner != &crate::ID || self.mutation.data_is_empty();
+        if !mutation_destroyed {
+            assert_keys_eq!(self.closer.key(), self.taker.key(), "closer");
+            require!(!receipt.is_pending(), ExecutionStillPending);
+
+            // locked vaults can only be unlocked by reversing the receipt, so it has to stay around
+            if receipt.is_complete() {
+                let mutation: Account<Mutation> = Account::try_from(&self.mutation)?;
+                require!(
+                    !mutation
+                        .config
+                        .taker_tokens()
+                        .any(|t| t.vault_action == VaultAction::Lock),
+                    ReceiptLocksVaults
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// closing a complete receipt also gives up the option to reverse it (not allowed while it keeps vaults locked)
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<CloseExecutionReceipt>) -> Result<()> {
+    close_account(
+        &mut ctx.accounts.execution_receipt.to_account_info(),
+        &mut ctx.accounts.taker.to_account_info(),
+    )
+}
@@ -0,0 +1,106 @@
+//! Retires a transmuter once all its mutations are destroyed.
+//! Optionally hands the banks' manager role over to a wallet of choice - otherwise they stay
+//! managed by the authority pda, which nobody can sign for once the transmuter is gone.
+//! Extra banks are passed as remaining accounts, in transmuter.extra_banks order.
+
+use crate::*;
+use anchor_lang::solana_program::account_info::next_account_info;
+use gem_bank::{self, cpi::accounts::UpdateBankManager, program::GemBank};
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8)]
+pub struct CloseTransmuter<'info> {
+    // transmuter
+    #[account(mut, close = owner,
+        has_one = authority,
+        has_one = owner,
+        has_one = bank_a,
+        has_one = bank_b,
+        has_one = bank_c,
+    )]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
+    pub authority: AccountInfo<'info>,
+
+    // cpi
+    /// CHECK:
+    #[account(mut)]
+    pub bank_a: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub bank_b: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub bank_c: AccountInfo<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+}
+
+impl<'info> CloseTransmuter<'info> {
+    fn update_bank_manager_ctx(
+        &self,
+        bank: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateBankManager<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            UpdateBankManager {
+                bank,
+                bank_manager: self.authority.clone(),
+            },
+        )
+    }
+
+    fn hand_over_bank(&self, bank: AccountInfo<'info>, new_bank_manager: Pubkey) -> Result<()> {
+        gem_bank::cpi::update_bank_manager(
+            self.update_bank_manager_ctx(bank)
+                .with_signer(&[&self.transmuter.get_seeds()]),
+            new_bank_manager,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for CloseTransmuter<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(!self.transmuter.has_mutations(), TransmuterHasMutations);
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, CloseTransmuter<'info>>,
+    new_bank_manager: Option<Pubkey>,
+) -> Result<()> {
+    let new_bank_manager = match new_bank_manager {
+        Some(new_bank_manager) => new_bank_manager,
+        None => return Ok(()),
+    };
+
+    // skipped banks are Pubkey::default()
+    for bank in [
+        &ctx.accounts.bank_a,
+        &ctx.accounts.bank_b,
+        &ctx.accounts.bank_c,
+    ] {
+        if bank.key() != Pubkey::default() {
+            ctx.accounts
+                .hand_over_bank(bank.clone(), new_bank_manager)?;
+        }
+    }
+
+    let extra_accounts = &mut ctx.remaining_accounts.iter();
+    for extra_bank in ctx.accounts.transmuter.extra_banks {
+        if extra_bank == Pubkey::default() {
+            continue;
+        }
+
+        let bank = next_account_info(extra_accounts)?;
+        assert_keys_eq!(bank.key(), extra_bank, "extra bank");
+        ctx.accounts
+            .hand_over_bank(bank.clone(), new_bank_manager)?;
+    }
+
+    Ok(())
+}
@@ -1,13 +1,19 @@
 //! What happens on mutation destruction?
+//! 0) Refused while any executions are pending, so that takers can still complete them
+//!    (pause the mutation to stop new executions from starting, then force cancel any the takers abandon)
 //! 1) Escrow accounts are drained to the owner (ATAs created)
+//!    (extra escrows are passed as remaining accounts - (escrow, dest, mint) for each)
+//!    (minted tokens have no escrow, except for a, which is created but never funded)
 //! 2) Escrow accounts are closed and SOL credited to the owner
 //! 3) Mutation state account is closed and SOL credited to the owner
+//!    (metadata template & nft template list, if any, are closed to the admin destroying the mutation)
 //! 4) Any vaults created by takers for this mutation STAY UNTOUCHED. This means:
 //!    - if they were locked, they stay locked (transmuter authority still controls them)
 //!    - if they were unlocked & owned by taker, taker can withdraw at any point
 //!    - if they were unlocked & owned by maker, maker can withdraw at any point
 
 use crate::*;
+use anchor_lang::solana_program::account_info::next_account_info;
 use anchor_spl::associated_token::{AssociatedToken, Create};
 use anchor_spl::token::{CloseAccount, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::{associated_token, token};
@@ -16,14 +22,21 @@ use anchor_spl::{associated_token, token};
 #[instruction(bump_auth: u8)]
 pub struct DestroyMutation<'info> {
     // mutation
-    #[account(has_one = authority, has_one = owner)]
+    #[account(mut, has_one = authority, has_one = owner)]
     pub transmuter: Box<Account<'info, Transmuter>>,
     #[account(mut, close = owner,
         has_one = transmuter,
         has_one = token_a_escrow,
     )] //other 2 escrows conditionally checked in handler
     pub mutation: Box<Account<'info, Mutation>>,
-    pub owner: Signer<'info>,
+    /// CHECK: receives the escrowed tokens & rent
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    /// owner, or a member with the mutation manager role (pays for any missing ATAs)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
     /// CHECK:
     #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
     pub authority: AccountInfo<'info>,
@@ -53,6 +66,14 @@ pub struct DestroyMutation<'info> {
     pub token_c_dest: AccountInfo<'info>, //skip deser coz might be empty
     pub token_c_mint: Box<Account<'info, Mint>>,
 
+    // templates (might not exist / be fake)
+    /// CHECK:
+    #[account(mut)]
+    pub metadata_template: AccountInfo<'info>, //checked in handler
+    /// CHECK:
+    #[account(mut)]
+    pub nft_template_list: AccountInfo<'info>, //checked in handler
+
     // misc
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -84,7 +105,7 @@ impl<'info> DestroyMutation<'info> {
         CpiContext::new(
             self.associated_token_program.to_account_info(),
             Create {
-                payer: self.owner.to_account_info(),
+                payer: self.admin.to_account_info(),
                 associated_token: token_ata,
                 authority: self.owner.to_account_info(),
                 mint: token_mint,
@@ -112,6 +133,18 @@ impl<'info> DestroyMutation<'info> {
 
 impl<'info> Validate<'info> for DestroyMutation<'info> {
     fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )?;
+
+        require!(
+            self.mutation.pending_executions == 0,
+            MutationHasPendingExecutions
+        );
+
         if let Some(b_escrow) = self.mutation.token_b_escrow {
             assert_keys_eq!(self.token_b_escrow.key(), b_escrow, "b escrow");
         }
@@ -125,7 +158,11 @@ impl<'info> Validate<'info> for DestroyMutation<'info> {
 }
 
 #[access_control(ctx.accounts.validate())]
-pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DestroyMutation<'info>>,
+) -> Result<()> {
+    ctx.accounts.transmuter.record_mutation_destroyed()?;
+
     // --------------------------------------- create any necessary destination ATAs
 
     let config = ctx.accounts.mutation.config;
@@ -139,7 +176,7 @@ pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
     }
 
     let token_b_ata = ctx.accounts.token_b_dest.to_account_info();
-    if config.maker_token_b.is_some() && token_b_ata.data_is_empty() {
+    if ctx.accounts.mutation.token_b_escrow.is_some() && token_b_ata.data_is_empty() {
         associated_token::create(
             ctx.accounts
                 .create_ata_ctx(token_b_ata, ctx.accounts.token_b_mint.to_account_info()),
@@ -147,7 +184,7 @@ pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
     }
 
     let token_c_ata = ctx.accounts.token_c_dest.to_account_info();
-    if config.maker_token_c.is_some() && token_c_ata.data_is_empty() {
+    if ctx.accounts.mutation.token_c_escrow.is_some() && token_c_ata.data_is_empty() {
         associated_token::create(
             ctx.accounts
                 .create_ata_ctx(token_c_ata, ctx.accounts.token_c_mint.to_account_info()),
@@ -173,7 +210,7 @@ pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
     )?;
 
     // second token
-    if config.maker_token_b.is_some() {
+    if ctx.accounts.mutation.token_b_escrow.is_some() {
         let escrow_b = ctx.accounts.token_b_escrow.to_account_info();
         let escrow_b_acc: Account<TokenAccount> = Account::try_from(&escrow_b)?;
         let dest_b = ctx.accounts.token_b_dest.to_account_info();
@@ -193,7 +230,7 @@ pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
     }
 
     // third token
-    if config.maker_token_c.is_some() {
+    if ctx.accounts.mutation.token_c_escrow.is_some() {
         let escrow_c = ctx.accounts.token_c_escrow.to_account_info();
         let escrow_c_acc: Account<TokenAccount> = Account::try_from(&escrow_c)?;
         let dest_c = ctx.accounts.token_c_dest.to_account_info();
@@ -212,5 +249,74 @@ pub fn handler(ctx: Context<DestroyMutation>) -> Result<()> {
         )?;
     }
 
+    // --------------------------------------- close templates
+
+    let mutation = ctx.accounts.mutation.key();
+    let (metadata_template, _) = Pubkey::find_program_address(
+        &[b"metadata_template".as_ref(), mutation.as_ref()],
+        &crate::ID,
+    );
+    assert_keys_eq!(
+        ctx.accounts.metadata_template.key(),
+        metadata_template,
+        "metadata template"
+    );
+    if !ctx.accounts.metadata_template.data_is_empty() {
+        close_account(
+            &mut ctx.accounts.metadata_template.to_account_info(),
+            &mut ctx.accounts.admin.to_account_info(),
+        )?;
+    }
+
+    if let Some(nft_template_list) = ctx.accounts.mutation.nft_template_list {
+        assert_keys_eq!(
+            ctx.accounts.nft_template_list.key(),
+            nft_template_list,
+            "nft template list"
+        );
+        close_account(
+            &mut ctx.accounts.nft_template_list.to_account_info(),
+            &mut ctx.accounts.admin.to_account_info(),
+        )?;
+    }
+
+    // --------------------------------------- extra tokens
+
+    let extra_accounts = &mut ctx.remaining_accounts.iter();
+    for (i, maker_token) in config.extra_maker_tokens.iter().enumerate() {
+        if maker_token.is_none() {
+            continue;
+        }
+
+        let escrow = next_account_info(extra_accounts)?;
+        let dest = next_account_info(extra_accounts)?;
+        let mint = next_account_info(extra_accounts)?;
+
+        // minted tokens have no escrow
+        let extra_escrow = match ctx.accounts.mutation.extra_escrows[i] {
+            Some(extra_escrow) => extra_escrow,
+            None => continue,
+        };
+        assert_keys_eq!(escrow.key(), extra_escrow, "extra escrow");
+
+        if dest.data_is_empty() {
+            associated_token::create(ctx.accounts.create_ata_ctx(dest.clone(), mint.clone()))?;
+        }
+
+        let escrow_acc: Account<TokenAccount> = Account::try_from(escrow)?;
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx(escrow.clone(), dest.clone())
+                .with_signer(&[&ctx.accounts.transmuter.get_seeds()]),
+            escrow_acc.amount,
+        )?;
+
+        token::close_account(
+            ctx.accounts
+                .close_ctx(escrow.clone())
+                .with_signer(&[&ctx.accounts.transmuter.get_seeds()]),
+        )?;
+    }
+
     Ok(())
 }
@@ -1,12 +1,217 @@
 use crate::*;
-use anchor_lang::solana_program::{program::invoke, system_instruction};
+use anchor_lang::solana_program::account_info::next_account_info;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::{program::invoke, system_instruction, sysvar};
 use anchor_spl::associated_token;
-use anchor_spl::associated_token::{AssociatedToken, Create};
-use anchor_spl::token::{self, Token, Transfer};
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken, Create};
+use anchor_spl::token::{
+    self, Burn, CloseAccount, InitializeAccount, InitializeMint, Mint, MintTo, Token, TokenAccount,
+    Transfer,
+};
 use gem_bank::state::Vault;
 use gem_bank::{
-    self, cpi::accounts::SetVaultLock, cpi::accounts::UpdateVaultOwner, program::GemBank,
+    self, cpi::accounts::SetVaultLock, cpi::accounts::UpdateVaultOwner, cpi::accounts::WithdrawGem,
+    program::GemBank,
+};
+use mpl_token_metadata::instruction::{
+    create_master_edition_v3, create_metadata_accounts_v2, update_metadata_accounts_v2,
 };
+use mpl_token_metadata::state::{Creator, Metadata};
+
+/// one of the mutation's taker slots in use, with its accounts
+pub struct TakerSlot<'info> {
+    /// position among all taker slots (a, b, c, then the extra ones)
+    pub index: u8,
+    pub config: TakerTokenConfig,
+    pub bank: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+}
+
+/// one of the mutation's maker slots in use, with its accounts
+pub struct MakerSlot<'info> {
+    /// position among all maker slots (a, b, c, then the extra ones)
+    pub index: u8,
+    pub config: MakerTokenConfig,
+    pub escrow: AccountInfo<'info>,
+    pub taker_ata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+}
+
+/// gem bank pda bumps for one of the vault's gem boxes, passed by the client
+/// (deriving them on chain for every box is too expensive)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct GemBoxBumps {
+    pub gem_box: u8,
+    pub gem_deposit_receipt: u8,
+    pub gem_rarity: u8,
+}
+
+/// accounts needed to withdraw & burn the gems in one of the vault's gem boxes
+pub struct BurnGroup<'info> {
+    pub gem_box: AccountInfo<'info>,
+    pub gem_deposit_receipt: AccountInfo<'info>,
+    pub gem_mint: AccountInfo<'info>,
+    pub gem_rarity: AccountInfo<'info>,
+    pub gem_destination: AccountInfo<'info>, //taker's ATA, created by gem bank if missing
+    /// checked by gem bank
+    pub bumps: GemBoxBumps,
+}
+
+/// accounts needed to update the metadata of the NFT in one of the vault's gem boxes
+pub struct MetadataGroup<'info> {
+    pub gem_box: AccountInfo<'info>, //proves the NFT is in the vault
+    pub metadata: AccountInfo<'info>,
+}
+
+/// everything needed by vault actions that act on the gems themselves, for a single vault
+#[derive(Default)]
+pub struct VaultGems<'info> {
+    /// Burn only
+    pub vault_authority: Option<AccountInfo<'info>>,
+    pub bump_auth: u8,
+    pub burn_groups: Vec<BurnGroup<'info>>,
+    /// UpdateMetadata only
+    pub metadata_groups: Vec<MetadataGroup<'info>>,
+}
+
+/// every slot in use, in slot order
+#[derive(Default)]
+pub struct Slots<'info> {
+    pub taker: Vec<TakerSlot<'info>>,
+    pub maker: Vec<MakerSlot<'info>>,
+}
+
+/// the transmuter authority manages the taker banks, so it's the one (un)locking their vaults
+pub fn set_vault_lock<'info>(
+    gem_bank: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    transmuter: &Transmuter,
+    bank: AccountInfo<'info>,
+    vault: AccountInfo<'info>,
+    locked: bool,
+) -> Result<()> {
+    gem_bank::cpi::set_vault_lock(
+        CpiContext::new(
+            gem_bank,
+            SetVaultLock {
+                bank,
+                vault,
+                bank_manager: authority,
+            },
+        )
+        .with_signer(&[&transmuter.get_seeds()]),
+        locked,
+    )
+}
+
+/// slot a comes with the instruction's own (bank, vault), every other taker slot in use
+/// as (bank, vault) in remaining accounts, in slot order - unused slots take no accounts
+pub fn load_taker_slots<'a, 'info: 'a>(
+    config: &MutationConfig,
+    execution_receipt: &ExecutionReceipt,
+    bank_a: AccountInfo<'info>,
+    vault_a: AccountInfo<'info>,
+    accounts: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+) -> Result<Vec<TakerSlot<'info>>> {
+    let mut slots = vec![];
+    for (index, taker_token) in config.taker_slots() {
+        let (bank, vault) = if index == 0 {
+            (bank_a.clone(), vault_a.clone())
+        } else {
+            let bank = next_account_info(accounts)?;
+            let vault = next_account_info(accounts)?;
+            (bank.clone(), vault.clone())
+        };
+
+        taker_token.assert_correct_bank(bank.key())?;
+        assert_keys_eq!(
+            vault.key(),
+            execution_receipt.taker_vault(index).unwrap_or_default(),
+            "vault doesn't match that on ER"
+        );
+
+        slots.push(TakerSlot {
+            index,
+            config: taker_token,
+            bank,
+            vault,
+        });
+    }
+    Ok(slots)
+}
+
+/// everything needed to move price tokens & close the payment escrow,
+/// shared by the instructions that pay out or refund a pending execution's payment
+pub struct PriceTokens<'a, 'info> {
+    pub transmuter: &'a Transmuter,
+    pub authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub escrow: AccountInfo<'info>,
+    /// gets the escrow's rent back, since they paid for it
+    pub taker: AccountInfo<'info>,
+    /// pays for destination ATAs that don't exist yet
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+impl<'a, 'info> PriceTokens<'a, 'info> {
+    /// moves price tokens, creating the destination ATA if it doesn't exist yet
+    /// (signed by the transmuter authority, in case they come out of the payment escrow)
+    pub fn transfer(
+        &self,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        dest_authority: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        if to.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                self.associated_token_program.clone(),
+                Create {
+                    payer: self.payer.clone(),
+                    associated_token: to.clone(),
+                    authority: dest_authority,
+                    mint: self.mint.clone(),
+                    system_program: self.system_program.clone(),
+                    token_program: self.token_program.clone(),
+                    rent: self.rent.clone(),
+                },
+            ))?;
+        }
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.clone(),
+                Transfer {
+                    from,
+                    to,
+                    authority,
+                },
+            )
+            .with_signer(&[&self.transmuter.get_seeds()]),
+            amount,
+        )
+    }
+
+    /// escrow is emptied by now, rent goes back to the taker who paid for it
+    pub fn close_escrow(&self) -> Result<()> {
+        token::close_account(
+            CpiContext::new(
+                self.token_program.clone(),
+                CloseAccount {
+                    account: self.escrow.clone(),
+                    destination: self.taker.clone(),
+                    authority: self.authority.clone(),
+                },
+            )
+            .with_signer(&[&self.transmuter.get_seeds()]),
+        )
+    }
+}
 
 #[derive(Accounts)]
 pub struct ExecuteMutation<'info> {
@@ -16,7 +221,7 @@ pub struct ExecuteMutation<'info> {
     #[account(mut,
         has_one = transmuter,
         has_one = token_a_escrow,
-    )] //other 2 escrows conditionally checked in handler
+    )] //other escrows checked as their slots are loaded
     pub mutation: Box<Account<'info, Mutation>>,
     /// CHECK:
     #[account(mut)]
@@ -25,51 +230,67 @@ pub struct ExecuteMutation<'info> {
     /// CHECK:
     pub authority: AccountInfo<'info>,
 
-    // taker banks + vaults (B and C might be fake - cheaper (compute) than making them optional)
+    // taker bank + vault of slot a (every other slot in use is passed via remaining accounts)
     /// CHECK:
     pub bank_a: AccountInfo<'info>,
     #[account(mut)]
     pub vault_a: Box<Account<'info, Vault>>,
+    pub gem_bank: Program<'info, GemBank>,
+    // only used for UpdateMetadata vault actions (might be fake otherwise)
     /// CHECK:
-    pub bank_b: AccountInfo<'info>,
+    pub metadata_template: AccountInfo<'info>, //checked when used
+    /// CHECK:
+    pub token_metadata_program: AccountInfo<'info>, //checked when used
+
+    // tokens of slot a (every other slot in use is passed via remaining accounts)
+    // skipping deserialization due to compute. Ok coz:
     /// CHECK:
     #[account(mut)]
-    pub vault_b: AccountInfo<'info>,
+    pub token_a_escrow: AccountInfo<'info>, //has_one check enough
     /// CHECK:
-    pub bank_c: AccountInfo<'info>,
+    #[account(mut)]
+    pub token_a_taker_ata: AccountInfo<'info>, //if not a TA, transfer will fail
+    // mut for minted tokens, which are minted on execution & burned on reversal
     /// CHECK:
     #[account(mut)]
-    pub vault_c: AccountInfo<'info>,
-    pub gem_bank: Program<'info, GemBank>,
+    pub token_a_mint: AccountInfo<'info>, //if wrong mint, transfer will fail (checked explicitly if minted)
 
-    // tokens - skipping deserialization due to compute. Ok coz:
-    // a
+    // payment in spl tokens (might be fake if mutation is only priced in lamports)
+    /// CHECK:
+    pub price_mint: AccountInfo<'info>, //checked in validate
     /// CHECK:
     #[account(mut)]
-    pub token_a_escrow: AccountInfo<'info>, //has_one check enough
+    pub price_taker_ata: AccountInfo<'info>, //if not a TA, transfer will fail
     /// CHECK:
     #[account(mut)]
-    pub token_a_taker_ata: AccountInfo<'info>, //if not a TA, transfer will fail
+    pub price_owner_ata: AccountInfo<'info>, //checked in validate
     /// CHECK:
-    pub token_a_mint: AccountInfo<'info>, //if wrong mint, transfer will fail
-    // b
+    #[account(mut)]
+    pub price_escrow: AccountInfo<'info>, //pda, holds the price tokens while pending, checked when used
+
+    // protocol execution fee
+    #[account(seeds = [b"protocol_config".as_ref()], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
     #[account(mut)]
-    pub token_b_escrow: AccountInfo<'info>,
+    pub protocol_fee_recipient: AccountInfo<'info>, //checked when used, might be fake if no fee
+
+    // nft reward (might be fake if the mutation doesn't mint one)
     /// CHECK:
     #[account(mut)]
-    pub token_b_taker_ata: AccountInfo<'info>,
+    pub nft_template_list: AccountInfo<'info>, //checked when used
     /// CHECK:
-    pub token_b_mint: AccountInfo<'info>,
-    // c
+    #[account(mut)]
+    pub nft_mint: AccountInfo<'info>, //pda, created on completion
     /// CHECK:
     #[account(mut)]
-    pub token_c_escrow: AccountInfo<'info>,
+    pub nft_taker_ata: AccountInfo<'info>, //created on completion
     /// CHECK:
     #[account(mut)]
-    pub token_c_taker_ata: AccountInfo<'info>,
+    pub nft_metadata: AccountInfo<'info>, //checked by token metadata program
     /// CHECK:
-    pub token_c_mint: AccountInfo<'info>,
+    #[account(mut)]
+    pub nft_master_edition: AccountInfo<'info>, //checked by token metadata program
 
     // misc
     #[account(mut)]
@@ -80,39 +301,163 @@ pub struct ExecuteMutation<'info> {
         constraint = execution_receipt.to_account_info().owner == program_id,
         has_one = taker, has_one = mutation)]
     pub execution_receipt: Box<Account<'info, ExecutionReceipt>>,
+    // shared by all of the taker's executions of this mutation, hence init_if_needed
+    #[account(init_if_needed, seeds = [
+            b"taker_record".as_ref(),
+            mutation.key().as_ref(),
+            taker.key().as_ref(),
+        ],
+        bump,
+        payer = taker,
+        space = 8 + std::mem::size_of::<TakerRecord>())]
+    pub taker_record: Box<Account<'info, TakerRecord>>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+    // only read for mutations with a roll, parsed manually (too big to deserialize)
+    /// CHECK:
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
 }
 
 impl<'info> ExecuteMutation<'info> {
-    fn set_vault_lock_ctx(
+    fn set_vault_lock(
+        &self,
+        bank: AccountInfo<'info>,
+        vault: AccountInfo<'info>,
+        locked: bool,
+    ) -> Result<()> {
+        set_vault_lock(
+            self.gem_bank.to_account_info(),
+            self.authority.clone(),
+            &self.transmuter,
+            bank,
+            vault,
+            locked,
+        )
+    }
+
+    fn price_tokens(&self) -> PriceTokens<'_, 'info> {
+        PriceTokens {
+            transmuter: &self.transmuter,
+            authority: self.authority.clone(),
+            mint: self.price_mint.clone(),
+            escrow: self.price_escrow.clone(),
+            taker: self.taker.to_account_info(),
+            payer: self.taker.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+
+    fn change_vault_owner_ctx(
         &self,
         bank: AccountInfo<'info>,
         vault: AccountInfo<'info>,
-    ) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+    ) -> CpiContext<'_, '_, '_, 'info, UpdateVaultOwner<'info>> {
         CpiContext::new(
             self.gem_bank.to_account_info(),
-            SetVaultLock {
+            UpdateVaultOwner {
                 bank,
                 vault,
-                bank_manager: self.authority.clone(),
+                owner: self.taker.to_account_info(),
             },
         )
     }
 
-    fn change_vault_owner_ctx(
+    fn withdraw_gem_ctx(
         &self,
         bank: AccountInfo<'info>,
         vault: AccountInfo<'info>,
-    ) -> CpiContext<'_, '_, '_, 'info, UpdateVaultOwner<'info>> {
+        vault_authority: AccountInfo<'info>,
+        group: &BurnGroup<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, WithdrawGem<'info>> {
         CpiContext::new(
             self.gem_bank.to_account_info(),
-            UpdateVaultOwner {
+            WithdrawGem {
                 bank,
                 vault,
                 owner: self.taker.to_account_info(),
+                authority: vault_authority,
+                gem_box: group.gem_box.clone(),
+                gem_deposit_receipt: group.gem_deposit_receipt.clone(),
+                gem_destination: group.gem_destination.clone(),
+                gem_mint: group.gem_mint.clone(),
+                gem_rarity: group.gem_rarity.clone(),
+                receiver: self.taker.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn burn_ctx(
+        &self,
+        to: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint,
+                to,
+                authority: self.taker.to_account_info(),
+            },
+        )
+    }
+
+    fn mint_to_ctx(
+        &self,
+        mint: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint,
+                to,
+                authority: self.authority.clone(),
+            },
+        )
+    }
+
+    fn init_mint_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitializeMint<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeMint {
+                mint: self.nft_mint.clone(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn init_payment_escrow_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitializeAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeAccount {
+                account: self.price_escrow.clone(),
+                mint: self.price_mint.clone(),
+                authority: self.authority.clone(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn close_ctx(
+        &self,
+        account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account,
+                destination: self.taker.to_account_info(),
+                authority: self.taker.to_account_info(),
             },
         )
     }
@@ -137,13 +482,14 @@ impl<'info> ExecuteMutation<'info> {
         &self,
         token_ata: AccountInfo<'info>,
         token_mint: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
     ) -> CpiContext<'_, '_, '_, 'info, Create<'info>> {
         CpiContext::new(
             self.associated_token_program.to_account_info(),
             Create {
                 payer: self.taker.to_account_info(),
                 associated_token: token_ata,
-                authority: self.taker.to_account_info(),
+                authority,
                 mint: token_mint,
                 system_program: self.system_program.to_account_info(),
                 token_program: self.token_program.to_account_info(),
@@ -165,6 +511,394 @@ impl<'info> ExecuteMutation<'info> {
         .map_err(Into::into)
     }
 
+    fn transfer_price_tokens(
+        &self,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        dest_authority: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        self.price_tokens()
+            .transfer(from, to, authority, dest_authority, amount)
+    }
+
+    /// moves price tokens owner -> taker, for reversal refunds
+    pub fn refund_token_payment(&self, amount: u64) -> Result<()> {
+        self.transfer_price_tokens(
+            self.price_owner_ata.clone(),
+            self.price_taker_ata.clone(),
+            self.owner.clone(),
+            self.taker.to_account_info(),
+            amount,
+        )
+    }
+
+    /// pays out of the taker's wallet, or out of the lamports escrowed on the receipt
+    fn pay_lamports(&self, to: AccountInfo<'info>, lamports: u64, from_escrow: bool) -> Result<()> {
+        if !from_escrow {
+            return self.make_payment(self.taker.to_account_info(), to, lamports);
+        }
+
+        withdraw_lamports(&self.execution_receipt.to_account_info(), &to, lamports)
+    }
+
+    /// pays out of the taker's price ATA, or out of the payment escrow
+    fn pay_tokens(
+        &self,
+        to: AccountInfo<'info>,
+        dest_authority: AccountInfo<'info>,
+        amount: u64,
+        from_escrow: bool,
+    ) -> Result<()> {
+        if from_escrow {
+            self.transfer_price_tokens(
+                self.price_escrow.clone(),
+                to,
+                self.authority.clone(),
+                dest_authority,
+                amount,
+            )
+        } else {
+            self.transfer_price_tokens(
+                self.price_taker_ata.clone(),
+                to,
+                self.taker.to_account_info(),
+                dest_authority,
+                amount,
+            )
+        }
+    }
+
+    /// price tokens of a pending execution are held in a token account pda, one per receipt
+    fn payment_escrow_bump(&self) -> Result<u8> {
+        let receipt = self.execution_receipt.key();
+        let (payment_escrow, bump) = Pubkey::find_program_address(
+            &[b"payment_escrow".as_ref(), receipt.as_ref()],
+            &crate::ID,
+        );
+        assert_keys_eq!(self.price_escrow.key(), payment_escrow, "payment escrow");
+        Ok(bump)
+    }
+
+    /// holds the payment of an execution that goes pending, until it's completed or cancelled:
+    /// lamports on the receipt itself, price tokens in the payment escrow (created here, paid for by the taker)
+    pub fn escrow_payment(&self, lamports: u64, token_amount: u64) -> Result<()> {
+        if lamports > 0 {
+            self.make_payment(
+                self.taker.to_account_info(),
+                self.execution_receipt.to_account_info(),
+                lamports,
+            )?;
+        }
+
+        if token_amount > 0 {
+            let bump = self.payment_escrow_bump()?;
+            if self.price_escrow.data_is_empty() {
+                let receipt = self.execution_receipt.key();
+                create_pda_with_space(
+                    &[b"payment_escrow".as_ref(), receipt.as_ref(), &[bump]],
+                    &self.price_escrow,
+                    TokenAccount::LEN,
+                    &spl_token::id(),
+                    &self.taker.to_account_info(),
+                    &self.system_program.to_account_info(),
+                )?;
+                token::initialize_account(self.init_payment_escrow_ctx())?;
+            }
+            token::transfer(
+                self.transfer_ctx(
+                    self.price_taker_ata.clone(),
+                    self.price_escrow.clone(),
+                    self.taker.to_account_info(),
+                ),
+                token_amount,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// pays the escrowed payment of a completed execution out to the maker
+    pub fn release_escrowed_payment(
+        &self,
+        lamports: u64,
+        token_amount: u64,
+        recipient_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if lamports > 0 || token_amount > 0 {
+            self.pay_maker(lamports, token_amount, recipient_accounts, true)?;
+        }
+        self.close_payment_escrow(token_amount)
+    }
+
+    /// hands the escrowed payment of a cancelled execution back to the taker,
+    /// except for the cancellation penalty, which the maker gets
+    pub fn refund_escrowed_payment(
+        &self,
+        lamports: u64,
+        token_amount: u64,
+        penalty_bps: u64,
+        recipient_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let penalty = apply_bps(lamports, penalty_bps)?;
+        let token_penalty = apply_bps(token_amount, penalty_bps)?;
+
+        let refund = lamports.try_sub(penalty)?;
+        if refund > 0 {
+            self.pay_lamports(self.taker.to_account_info(), refund, true)?;
+        }
+        let token_refund = token_amount.try_sub(token_penalty)?;
+        if token_refund > 0 {
+            self.pay_tokens(
+                self.price_taker_ata.clone(),
+                self.taker.to_account_info(),
+                token_refund,
+                true,
+            )?;
+        }
+
+        if penalty > 0 || token_penalty > 0 {
+            self.pay_maker(penalty, token_penalty, recipient_accounts, true)?;
+        }
+        self.close_payment_escrow(token_amount)
+    }
+
+    fn close_payment_escrow(&self, token_amount: u64) -> Result<()> {
+        if token_amount == 0 {
+            return Ok(());
+        }
+        self.price_tokens().close_escrow()
+    }
+
+    /// slot a uses the instruction's own accounts, every other slot in use comes first in remaining accounts:
+    /// (bank, vault) for each taker slot, then (escrow, taker ata, mint) for each maker slot (mint writable if minted),
+    /// in slot order (b, c, then the extra ones). Returns the accounts left over
+    pub fn load_slots<'c>(
+        &self,
+        remaining_accounts: &'c [AccountInfo<'info>],
+    ) -> Result<(Slots<'info>, &'c [AccountInfo<'info>])> {
+        let config = self.mutation.config;
+        let accounts = &mut remaining_accounts.iter();
+        let mut slots = Slots::default();
+
+        slots.taker = load_taker_slots(
+            &config,
+            &self.execution_receipt,
+            self.bank_a.clone(),
+            self.vault_a.to_account_info(),
+            accounts,
+        )?;
+        for slot in slots.taker.iter() {
+            let vault: Account<'_, Vault> = Account::try_from(&slot.vault)?;
+            slot.config.assert_sufficient_amount(&vault)?;
+        }
+
+        for (index, maker_token) in config.maker_slots() {
+            let (escrow, taker_ata, mint) = if index == 0 {
+                (
+                    self.token_a_escrow.clone(),
+                    self.token_a_taker_ata.clone(),
+                    self.token_a_mint.clone(),
+                )
+            } else {
+                let escrow = next_account_info(accounts)?;
+                let taker_ata = next_account_info(accounts)?;
+                let mint = next_account_info(accounts)?;
+                (escrow.clone(), taker_ata.clone(), mint.clone())
+            };
+
+            // minted tokens have no escrow
+            if let Some(maker_escrow) = self.mutation.maker_escrow(index) {
+                assert_keys_eq!(escrow.key(), maker_escrow, "maker escrow");
+            }
+
+            slots.maker.push(MakerSlot {
+                index,
+                config: maker_token,
+                escrow,
+                taker_ata,
+                mint,
+            });
+        }
+
+        Ok((slots, accounts.as_slice()))
+    }
+
+    /// vaults set to Burn need their gem bank authority, followed by
+    /// (gem box, deposit receipt, gem mint, gem rarity, taker's gem ATA) for each of their gem boxes.
+    /// Vaults set to UpdateMetadata need (gem box, metadata) for each of their gem boxes.
+    /// These come right after the slots in remaining accounts, in taker slot order,
+    /// and only on the call that completes the execution. Burned gem boxes also need their bumps,
+    /// in the same order. Returns one entry per taker slot in use + the accounts left over
+    pub fn load_vault_gems<'c>(
+        &self,
+        remaining_accounts: &'c [AccountInfo<'info>],
+        slots: &Slots<'info>,
+        gem_bumps: &[GemBoxBumps],
+    ) -> Result<(Vec<VaultGems<'info>>, &'c [AccountInfo<'info>])> {
+        let accounts = &mut remaining_accounts.iter();
+        let gem_bumps = &mut gem_bumps.iter();
+        let mut vault_gems = Vec::with_capacity(slots.taker.len());
+        for slot in slots.taker.iter() {
+            let mut gems = VaultGems::default();
+            match slot.config.vault_action {
+                VaultAction::Burn => {
+                    let vault_acc: Account<'_, Vault> = Account::try_from(&slot.vault)?;
+                    gems.vault_authority = Some(next_account_info(accounts)?.clone());
+                    gems.bump_auth = vault_acc.authority_bump_seed[0];
+                    for _ in 0..vault_acc.gem_box_count {
+                        gems.burn_groups.push(BurnGroup {
+                            gem_box: next_account_info(accounts)?.clone(),
+                            gem_deposit_receipt: next_account_info(accounts)?.clone(),
+                            gem_mint: next_account_info(accounts)?.clone(),
+                            gem_rarity: next_account_info(accounts)?.clone(),
+                            gem_destination: next_account_info(accounts)?.clone(),
+                            bumps: *gem_bumps
+                                .next()
+                                .ok_or_else(|| error!(ErrorCode::MissingVaultGems))?,
+                        });
+                    }
+                }
+                VaultAction::UpdateMetadata => {
+                    let vault_acc: Account<'_, Vault> = Account::try_from(&slot.vault)?;
+                    for _ in 0..vault_acc.gem_box_count {
+                        gems.metadata_groups.push(MetadataGroup {
+                            gem_box: next_account_info(accounts)?.clone(),
+                            metadata: next_account_info(accounts)?.clone(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+            vault_gems.push(gems);
+        }
+
+        Ok((vault_gems, accounts.as_slice()))
+    }
+
+    /// pays the owner, or splits the payment between the mutation's revenue split recipients
+    /// recipients are passed as remaining accounts (after the slots & vault gems): first their wallets, then (if paying in tokens) their price ATAs
+    /// Paid by the taker, or out of the escrow if the payment was held while pending
+    pub fn pay_maker(
+        &self,
+        lamports: u64,
+        token_amount: u64,
+        recipient_accounts: &[AccountInfo<'info>],
+        from_escrow: bool,
+    ) -> Result<()> {
+        // protocol takes its cut of lamport payments first, the maker gets the rest
+        let protocol_fee = self
+            .protocol_config
+            .execution_fee(lamports, self.transmuter.owner)?;
+        if protocol_fee > 0 {
+            assert_keys_eq!(
+                self.protocol_fee_recipient.key(),
+                self.protocol_config.execution_fee_recipient,
+                "protocol fee recipient"
+            );
+            self.pay_lamports(
+                self.protocol_fee_recipient.clone(),
+                protocol_fee,
+                from_escrow,
+            )?;
+        }
+        let lamports = lamports.try_sub(protocol_fee)?;
+
+        let splits = self.mutation.active_revenue_splits();
+
+        if splits.is_empty() {
+            if lamports > 0 {
+                self.pay_lamports(self.owner.clone(), lamports, from_escrow)?;
+            }
+            if token_amount > 0 {
+                self.pay_tokens(
+                    self.price_owner_ata.clone(),
+                    self.owner.clone(),
+                    token_amount,
+                    from_escrow,
+                )?;
+            }
+            return Ok(());
+        }
+
+        let lamport_shares = RevenueSplit::split_amount(splits, lamports)?;
+        let token_shares = RevenueSplit::split_amount(splits, token_amount)?;
+        let accounts = &mut recipient_accounts.iter();
+
+        let mut wallets = Vec::with_capacity(splits.len());
+        for (split, share) in splits.iter().zip(lamport_shares) {
+            let wallet = next_account_info(accounts)?;
+            assert_keys_eq!(wallet.key(), split.recipient, "revenue split recipient");
+            if share > 0 {
+                self.pay_lamports(wallet.clone(), share, from_escrow)?;
+            }
+            wallets.push(wallet);
+        }
+
+        if token_amount > 0 {
+            let price_mint = self.price_mint.key();
+            for (wallet, share) in wallets.into_iter().zip(token_shares) {
+                let wallet_ata = next_account_info(accounts)?;
+                assert_keys_eq!(
+                    wallet_ata.key(),
+                    get_associated_token_address(&wallet.key(), &price_mint),
+                    "recipient's price ata"
+                );
+                if share > 0 {
+                    self.pay_tokens(wallet_ata.clone(), wallet.clone(), share, from_escrow)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// withdraws every gem in the vault to the taker, burns them and closes the emptied ATAs
+    /// (gem boxes & deposit receipts are closed by gem bank), so all rent ends up with the taker
+    fn burn_vault_gems(
+        &self,
+        bank: AccountInfo<'info>,
+        vault: AccountInfo<'info>,
+        vault_gems: &VaultGems<'info>,
+    ) -> Result<()> {
+        let vault_authority = vault_gems
+            .vault_authority
+            .clone()
+            .ok_or_else(|| error!(ErrorCode::MissingVaultGems))?;
+
+        for group in vault_gems.burn_groups.iter() {
+            let gem_box: Account<'_, TokenAccount> = Account::try_from(&group.gem_box)?;
+            let amount = gem_box.amount;
+
+            gem_bank::cpi::withdraw_gem(
+                self.withdraw_gem_ctx(bank.clone(), vault.clone(), vault_authority.clone(), group),
+                vault_gems.bump_auth,
+                group.bumps.gem_box,
+                group.bumps.gem_deposit_receipt,
+                group.bumps.gem_rarity,
+                amount,
+            )?;
+            token::burn(
+                self.burn_ctx(group.gem_destination.clone(), group.gem_mint.clone()),
+                amount,
+            )?;
+
+            // taker might've held some of the same (fungible) gems already, only close if empty
+            let gem_destination: Account<'_, TokenAccount> =
+                Account::try_from(&group.gem_destination)?;
+            if gem_destination.amount == 0 {
+                token::close_account(self.close_ctx(group.gem_destination.clone()))?;
+            }
+        }
+
+        // make sure no gem box was skipped
+        let vault_acc: Account<'_, Vault> = Account::try_from(&vault)?;
+        require!(vault_acc.gem_count == 0, VaultNotFullyBurned);
+
+        Ok(())
+    }
+
     pub fn perform_vault_action(
         &self,
         bank: AccountInfo<'info>,
@@ -172,16 +906,13 @@ impl<'info> ExecuteMutation<'info> {
         taker_token: TakerTokenConfig,
         new_vault_lock: bool,
         vault_previously_locked: bool,
+        vault_gems: &VaultGems<'info>,
     ) -> Result<()> {
         match taker_token.vault_action {
             VaultAction::ChangeOwner => {
                 // if was previously locked, need to unlock
                 if vault_previously_locked {
-                    gem_bank::cpi::set_vault_lock(
-                        self.set_vault_lock_ctx(bank.clone(), vault.clone())
-                            .with_signer(&[&self.transmuter.get_seeds()]),
-                        false,
-                    )?;
+                    self.set_vault_lock(bank.clone(), vault.clone(), false)?;
                 }
                 // default action
                 gem_bank::cpi::update_vault_owner(
@@ -195,46 +926,220 @@ impl<'info> ExecuteMutation<'info> {
                     return Ok(());
                 }
                 // default action
-                gem_bank::cpi::set_vault_lock(
-                    self.set_vault_lock_ctx(bank, vault)
-                        .with_signer(&[&self.transmuter.get_seeds()]),
-                    new_vault_lock,
-                )
+                self.set_vault_lock(bank, vault, new_vault_lock)
             }
             VaultAction::DoNothing => {
                 // if was previously locked, need to unlock
                 if vault_previously_locked {
-                    gem_bank::cpi::set_vault_lock(
-                        self.set_vault_lock_ctx(bank, vault)
-                            .with_signer(&[&self.transmuter.get_seeds()]),
-                        false,
-                    )?;
+                    self.set_vault_lock(bank, vault, false)?;
                 }
                 // default action
                 Ok(())
             }
+            VaultAction::Burn => {
+                // gem bank won't let gems out of a locked vault
+                if vault_previously_locked {
+                    self.set_vault_lock(bank.clone(), vault.clone(), false)?;
+                }
+                self.burn_vault_gems(bank, vault, vault_gems)
+            }
+            VaultAction::UpdateMetadata => {
+                // NFTs stay with the taker, so same as DoNothing
+                if vault_previously_locked {
+                    self.set_vault_lock(bank, vault.clone(), false)?;
+                }
+                self.update_vault_metadata(vault, vault_gems)
+            }
         }
     }
 
-    pub fn lock_vaults_for_mutatino_duration(&self, config: &MutationConfig) -> Result<()> {
-        gem_bank::cpi::set_vault_lock(
-            self.set_vault_lock_ctx(self.bank_a.clone(), self.vault_a.to_account_info())
+    /// rewrites the metadata of every NFT in the vault from the mutation's metadata template
+    fn update_vault_metadata(
+        &self,
+        vault: AccountInfo<'info>,
+        vault_gems: &VaultGems<'info>,
+    ) -> Result<()> {
+        assert_keys_eq!(
+            self.token_metadata_program.key(),
+            mpl_token_metadata::ID,
+            "token metadata program"
+        );
+        let template: Account<'_, MetadataTemplate> = Account::try_from(&self.metadata_template)?;
+        assert_keys_eq!(template.mutation, self.mutation.key(), "metadata template");
+
+        let vault_acc: Account<'_, Vault> = Account::try_from(&vault)?;
+        for group in vault_gems.metadata_groups.iter() {
+            let metadata = Metadata::from_account_info(&group.metadata)?;
+
+            // gem boxes are owned by the vault's authority, so holding the NFT proves it's in the vault
+            let gem_box: Account<'_, TokenAccount> = Account::try_from(&group.gem_box)?;
+            assert_keys_eq!(gem_box.owner, vault_acc.authority, "gem box owner");
+            assert_keys_eq!(gem_box.mint, metadata.mint, "gem box mint");
+            require!(gem_box.amount > 0, MissingVaultGems);
+
+            invoke_signed(
+                &update_metadata_accounts_v2(
+                    mpl_token_metadata::ID,
+                    group.metadata.key(),
+                    self.authority.key(),
+                    None,
+                    Some(template.render(&metadata)),
+                    None,
+                    None,
+                ),
+                &[group.metadata.clone(), self.authority.clone()],
+                &[&self.transmuter.get_seeds()],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn load_nft_template_list(&self) -> Result<Account<'info, NftTemplateList>> {
+        let template_list: Account<'info, NftTemplateList> =
+            Account::try_from(&self.nft_template_list)?;
+        assert_keys_eq!(
+            template_list.key(),
+            self.mutation.nft_template_list.unwrap_or_default(),
+            "nft template list"
+        );
+        Ok(template_list)
+    }
+
+    /// every started execution has to be able to mint its nft once completed
+    pub fn assert_nft_template_available(&self) -> Result<()> {
+        let template_list = self.load_nft_template_list()?;
+        require!(
+            template_list.available()? as u64 > self.mutation.pending_executions,
+            NftTemplatesExhausted
+        );
+        Ok(())
+    }
+
+    /// mints a fresh 1/1 (metadata + master edition) to the taker from the next template.
+    /// Transmuter authority is the (verified) creator and the update authority
+    pub fn mint_nft_reward(&self, roll_seed: Option<[u8; 32]>) -> Result<()> {
+        assert_keys_eq!(
+            self.token_metadata_program.key(),
+            mpl_token_metadata::ID,
+            "token metadata program"
+        );
+
+        // pick template
+        let mut template_list = self.load_nft_template_list()?;
+        let mint_index = template_list.minted.to_le_bytes();
+        let template = {
+            let mut data = self.nft_template_list.try_borrow_mut_data()?;
+            template_list.take_next(&mut data, self.mutation.config.nft_reward, roll_seed)?
+        };
+        template_list.exit(&crate::ID)?;
+
+        // create mint - a pda, one per template handed out
+        // (receipts can be closed & re-created at the same address, so they can't seed it)
+        let list = self.nft_template_list.key();
+        let (_, bump) = Pubkey::find_program_address(
+            &[b"nft_reward".as_ref(), list.as_ref(), &mint_index],
+            &crate::ID,
+        );
+        create_pda_with_space(
+            &[b"nft_reward".as_ref(), list.as_ref(), &mint_index, &[bump]],
+            &self.nft_mint,
+            Mint::LEN,
+            &spl_token::id(),
+            &self.taker.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+        token::initialize_mint(
+            self.init_mint_ctx(),
+            0,
+            self.authority.key,
+            Some(self.authority.key),
+        )?;
+
+        // mint to taker
+        associated_token::create(self.create_ata_ctx(
+            self.nft_taker_ata.clone(),
+            self.nft_mint.clone(),
+            self.taker.to_account_info(),
+        ))?;
+        token::mint_to(
+            self.mint_to_ctx(self.nft_mint.clone(), self.nft_taker_ata.clone())
                 .with_signer(&[&self.transmuter.get_seeds()]),
-            true,
+            1,
         )?;
-        if config.taker_token_b.is_some() {
-            gem_bank::cpi::set_vault_lock(
-                self.set_vault_lock_ctx(self.bank_b.clone(), self.vault_b.clone())
-                    .with_signer(&[&self.transmuter.get_seeds()]),
+
+        // metadata
+        let creators = vec![Creator {
+            address: self.authority.key(),
+            verified: true,
+            share: 100,
+        }];
+        invoke_signed(
+            &create_metadata_accounts_v2(
+                mpl_token_metadata::ID,
+                self.nft_metadata.key(),
+                self.nft_mint.key(),
+                self.authority.key(),
+                self.taker.key(),
+                self.authority.key(),
+                template.name,
+                template_list.symbol(),
+                template.uri,
+                Some(creators),
+                template_list.seller_fee_basis_points,
                 true,
-            )?;
-        }
-        if config.taker_token_c.is_some() {
-            gem_bank::cpi::set_vault_lock(
-                self.set_vault_lock_ctx(self.bank_c.clone(), self.vault_c.clone())
-                    .with_signer(&[&self.transmuter.get_seeds()]),
                 true,
-            )?;
+                None,
+                None,
+            ),
+            &[
+                self.nft_metadata.clone(),
+                self.nft_mint.clone(),
+                self.authority.clone(),
+                self.taker.to_account_info(),
+                self.system_program.to_account_info(),
+                self.rent.to_account_info(),
+                self.token_metadata_program.clone(),
+            ],
+            &[&self.transmuter.get_seeds()],
+        )?;
+
+        // master edition (takes over mint & freeze authority, so supply stays at 1)
+        invoke_signed(
+            &create_master_edition_v3(
+                mpl_token_metadata::ID,
+                self.nft_master_edition.key(),
+                self.nft_mint.key(),
+                self.authority.key(),
+                self.authority.key(),
+                self.nft_metadata.key(),
+                self.taker.key(),
+                Some(0),
+            ),
+            &[
+                self.nft_master_edition.clone(),
+                self.nft_mint.clone(),
+                self.authority.clone(),
+                self.taker.to_account_info(),
+                self.nft_metadata.clone(),
+                self.token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.rent.to_account_info(),
+                self.token_metadata_program.clone(),
+            ],
+            &[&self.transmuter.get_seeds()],
+        )
+        .map_err(Into::into)
+    }
+
+    pub fn lock_vaults_for_mutatino_duration(&self, slots: &Slots<'info>) -> Result<()> {
+        self.set_taker_vaults_lock(slots, true)
+    }
+
+    /// (un)locks all of the taker's vaults in use
+    pub fn set_taker_vaults_lock(&self, slots: &Slots<'info>, locked: bool) -> Result<()> {
+        for slot in slots.taker.iter() {
+            self.set_vault_lock(slot.bank.clone(), slot.vault.clone(), locked)?;
         }
         Ok(())
     }
@@ -243,9 +1148,23 @@ impl<'info> ExecuteMutation<'info> {
         &self,
         escrow: AccountInfo<'info>,
         taker_ata: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
         maker_token: MakerTokenConfig,
         reverse: bool,
     ) -> Result<()> {
+        if maker_token.is_minted() {
+            // authority might be able to mint other tokens too, so mint has to be checked
+            maker_token.assert_correct_mint(mint.key())?;
+            if reverse {
+                return token::burn(self.burn_ctx(taker_ata, mint), maker_token.amount_per_use);
+            }
+            return token::mint_to(
+                self.mint_to_ctx(mint, taker_ata)
+                    .with_signer(&[&self.transmuter.get_seeds()]),
+                maker_token.amount_per_use,
+            );
+        }
+
         if reverse {
             token::transfer(
                 self.transfer_ctx(taker_ata, escrow, self.taker.to_account_info()),
@@ -265,48 +1184,20 @@ impl<'info> Validate<'info> for ExecuteMutation<'info> {
     fn validate(&self) -> Result<()> {
         let config = self.mutation.config;
 
-        // validate banks & vaults
-        config
-            .taker_token_a
-            .assert_correct_bank(self.bank_a.key())?;
-        config
-            .taker_token_a
-            .assert_sufficient_amount(&self.vault_a)?;
-        assert_keys_eq!(
-            self.vault_a.key(),
-            self.execution_receipt.vault_a.unwrap(),
-            "vault doesn't match that on ER"
-        );
+        // banks, vaults & escrows are validated as the slots are loaded (see load_slots)
 
-        if let Some(taker_token_b) = config.taker_token_b {
-            let vault_b: Account<'_, Vault> = Account::try_from(&self.vault_b)?;
-            taker_token_b.assert_correct_bank(self.bank_b.key())?;
-            taker_token_b.assert_sufficient_amount(&vault_b)?;
+        // validate payment accounts
+        if let Some(price_mint) = config.price.price_mint {
+            assert_keys_eq!(self.price_mint.key(), price_mint, "price mint");
             assert_keys_eq!(
-                self.vault_b.key(),
-                self.execution_receipt.vault_b.unwrap(),
-                "vault doesn't match that on ER"
+                self.price_owner_ata.key(),
+                get_associated_token_address(&self.owner.key(), &price_mint),
+                "owner's price ata"
             );
         }
-
-        if let Some(taker_token_c) = config.taker_token_c {
-            let vault_c: Account<'_, Vault> = Account::try_from(&self.vault_c)?;
-            taker_token_c.assert_correct_bank(self.bank_c.key())?;
-            taker_token_c.assert_sufficient_amount(&vault_c)?;
-            assert_keys_eq!(
-                self.vault_c.key(),
-                self.execution_receipt.vault_c.unwrap(),
-                "vault doesn't match that on ER"
-            );
-        }
-
-        // validate escrows
-        if let Some(b_escrow) = self.mutation.token_b_escrow {
-            assert_keys_eq!(self.token_b_escrow.key(), b_escrow, "b escrow");
-        }
-
-        if let Some(c_escrow) = self.mutation.token_c_escrow {
-            assert_keys_eq!(self.token_c_escrow.key(), c_escrow, "c escrow");
+        // only used while price tokens are held for a pending execution
+        if self.execution_receipt.escrowed_amount > 0 {
+            self.payment_escrow_bump()?;
         }
 
         Ok(())
@@ -316,35 +1207,41 @@ impl<'info> Validate<'info> for ExecuteMutation<'info> {
 // #[access_control(ctx.accounts.validate())]
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ExecuteMutation<'info>>,
+    taker_proof: Option<TakerProof>,
+    gem_bumps: Vec<GemBoxBumps>,
 ) -> Result<()> {
     // todo temp moving here due to error
     ctx.accounts.validate()?;
+    let (slots, remaining_accounts) = ctx.accounts.load_slots(ctx.remaining_accounts)?;
 
-    // --------------------------------------- create any necessary ATAs
-    // tried factoring out as a fn, but somehow increases compute requirements
+    // burn accounts are only needed (and passed) on the call that completes the execution
+    let receipt = &ctx.accounts.execution_receipt;
+    let completes_now = receipt.is_pending()
+        || (receipt.is_not_started() && !ctx.accounts.mutation.config.needs_pending_phase());
+    let (vault_gems, split_accounts) = if completes_now {
+        ctx.accounts
+            .load_vault_gems(remaining_accounts, &slots, &gem_bumps)?
+    } else {
+        (vec![], remaining_accounts)
+    };
 
-    let token_a_ata = ctx.accounts.token_a_taker_ata.to_account_info();
-    if token_a_ata.data_is_empty() {
-        associated_token::create(
-            ctx.accounts
-                .create_ata_ctx(token_a_ata, ctx.accounts.token_a_mint.to_account_info()),
-        )?;
-    }
+    // --------------------------------------- create any necessary ATAs
 
-    let token_b_ata = ctx.accounts.token_b_taker_ata.to_account_info();
-    if ctx.accounts.mutation.config.maker_token_b.is_some() && token_b_ata.data_is_empty() {
-        associated_token::create(
-            ctx.accounts
-                .create_ata_ctx(token_b_ata, ctx.accounts.token_b_mint.to_account_info()),
-        )?;
+    for slot in slots.maker.iter() {
+        if slot.taker_ata.data_is_empty() {
+            associated_token::create(ctx.accounts.create_ata_ctx(
+                slot.taker_ata.clone(),
+                slot.mint.clone(),
+                ctx.accounts.taker.to_account_info(),
+            ))?;
+        }
     }
 
-    let token_c_ata = ctx.accounts.token_c_taker_ata.to_account_info();
-    if ctx.accounts.mutation.config.maker_token_c.is_some() && token_c_ata.data_is_empty() {
-        associated_token::create(
-            ctx.accounts
-                .create_ata_ctx(token_c_ata, ctx.accounts.token_c_mint.to_account_info()),
-        )?;
+    // make sure every started execution will have an nft template to mint from
+    if ctx.accounts.execution_receipt.is_not_started()
+        && ctx.accounts.mutation.config.nft_reward != NftRewardMode::None
+    {
+        ctx.accounts.assert_nft_template_available()?;
     }
 
     // --------------------------------------- execution receipt
@@ -352,40 +1249,67 @@ pub fn handler<'a, 'b, 'c, 'info>(
     let execution_receipt = &mut ctx.accounts.execution_receipt;
     let config = ctx.accounts.mutation.config;
     let mutation = &mut ctx.accounts.mutation;
-    let price = mutation.config.price.price_lamports;
 
     let mut vaults_previously_locked = false;
+    let mut roll_seed = None;
+    let mut escrowed_payment = None;
 
     match execution_receipt.state {
         ExecutionState::NotStarted => {
+            let now_ts = now_ts()?;
+            mutation.assert_not_paused(&ctx.accounts.transmuter)?;
+            config.assert_is_available(now_ts)?;
+            mutation
+                .taker_list
+                .assert_taker_allowed(ctx.accounts.taker.key(), taker_proof)?;
+
+            let taker_record = &mut ctx.accounts.taker_record;
+            taker_record.mutation = mutation.key();
+            taker_record.taker = ctx.accounts.taker.key();
+            taker_record.try_record_execution(&config, now_ts)?;
+
             execution_receipt.record_mutation_complete_ts(config.mutation_duration_sec)?;
 
             // only decrementing uses / take payment on 1st call, when mutation not yet started
+            // (price depends on uses consumed so far, so has to be computed before decrementing)
+            let (price, token_price) = mutation.current_price(now_ts)?;
             mutation.try_decrement_uses()?;
-            if price > 0 {
-                ctx.accounts.make_payment(
-                    ctx.accounts.taker.to_account_info(),
-                    ctx.accounts.owner.to_account_info(),
-                    price,
-                )?;
-            }
-
-            let execution_receipt = &mut ctx.accounts.execution_receipt;
 
-            // if need time to complete, mark pending and exit
-            if config.mutation_duration_sec > 0 {
+            // if need time to complete (or a roll to reveal), hold the payment, mark pending and exit
+            if config.needs_pending_phase() {
+                ctx.accounts.escrow_payment(price, token_price)?;
                 // mark pending
+                let execution_receipt = &mut ctx.accounts.execution_receipt;
+                execution_receipt.record_escrowed_payment(price, token_price);
                 execution_receipt.mark_pending();
+                execution_receipt.record_commit_slot()?;
+                ctx.accounts.mutation.start_pending_execution()?;
                 // lock vaults for duration of mutation
-                ctx.accounts.lock_vaults_for_mutatino_duration(&config)?;
+                ctx.accounts.lock_vaults_for_mutatino_duration(&slots)?;
                 return Ok(());
             }
-            // else mark complete and continue
-            execution_receipt.try_mark_complete()?;
+            // else pay, mark complete and continue
+            ctx.accounts
+                .pay_maker(price, token_price, split_accounts, false)?;
+            ctx.accounts.execution_receipt.try_mark_complete()?;
         }
         ExecutionState::Pending => {
+            // rolls are revealed from a slot hash that didn't exist when the execution was started
+            // (None if it's expired - loot rolls & random nft picks then resolve to a fixed fallback)
+            if config.needs_reveal() {
+                roll_seed = find_reveal_slot_hash(
+                    &ctx.accounts.slot_hashes,
+                    execution_receipt.commit_slot,
+                )?
+                .map(|slot_hash| reveal_seed(&slot_hash, &execution_receipt.key()));
+            }
             // will error out if time isn't due yet
             execution_receipt.try_mark_complete()?;
+            escrowed_payment = Some(execution_receipt.release_payment());
+            mutation.finish_pending_execution()?;
+            if config.loot_table.is_enabled() {
+                execution_receipt.loot_outcome = config.loot_table.resolve(roll_seed)?;
+            }
             // this is the only case where vaults were previously locked
             vaults_previously_locked = true;
         }
@@ -395,67 +1319,48 @@ pub fn handler<'a, 'b, 'c, 'info>(
         }
     }
 
-    // --------------------------------------- taker vaults
+    // --------------------------------------- payment held while pending
 
-    // first bank
-    ctx.accounts.perform_vault_action(
-        ctx.accounts.bank_a.to_account_info(),
-        ctx.accounts.vault_a.to_account_info(),
-        ctx.accounts.mutation.config.taker_token_a,
-        true,
-        vaults_previously_locked,
-    )?;
-
-    // second bank
-    if let Some(taker_token_b) = config.taker_token_b {
-        ctx.accounts.perform_vault_action(
-            ctx.accounts.bank_b.to_account_info(),
-            ctx.accounts.vault_b.to_account_info(),
-            taker_token_b,
-            true,
-            vaults_previously_locked,
-        )?;
+    if let Some((lamports, token_amount)) = escrowed_payment {
+        ctx.accounts
+            .release_escrowed_payment(lamports, token_amount, split_accounts)?;
     }
 
-    // third bank
-    if let Some(taker_token_c) = config.taker_token_c {
+    // --------------------------------------- taker vaults
+
+    for (i, slot) in slots.taker.iter().enumerate() {
         ctx.accounts.perform_vault_action(
-            ctx.accounts.bank_c.to_account_info(),
-            ctx.accounts.vault_c.to_account_info(),
-            taker_token_c,
+            slot.bank.clone(),
+            slot.vault.clone(),
+            slot.config,
             true,
             vaults_previously_locked,
+            &vault_gems[i],
         )?;
     }
 
     // --------------------------------------- move tokens
 
-    // first token
-    ctx.accounts.perform_token_transfer(
-        ctx.accounts.token_a_escrow.to_account_info(),
-        ctx.accounts.token_a_taker_ata.to_account_info(),
-        config.maker_token_a,
-        false,
-    )?;
+    // loot mutations only move the rolled slot
+    let loot_outcome = ctx.accounts.execution_receipt.loot_outcome;
 
-    // second token
-    if let Some(maker_token_b) = config.maker_token_b {
+    for slot in slots.maker.iter() {
+        if !config.hands_out_maker_slot(slot.index, loot_outcome) {
+            continue;
+        }
         ctx.accounts.perform_token_transfer(
-            ctx.accounts.token_b_escrow.to_account_info(),
-            ctx.accounts.token_b_taker_ata.to_account_info(),
-            maker_token_b,
+            slot.escrow.clone(),
+            slot.taker_ata.clone(),
+            slot.mint.clone(),
+            slot.config,
             false,
         )?;
     }
 
-    // third token
-    if let Some(maker_token_c) = config.maker_token_c {
-        ctx.accounts.perform_token_transfer(
-            ctx.accounts.token_c_escrow.to_account_info(),
-            ctx.accounts.token_c_taker_ata.to_account_info(),
-            maker_token_c,
-            false,
-        )?;
+    // --------------------------------------- nft reward
+
+    if config.nft_reward != NftRewardMode::None {
+        ctx.accounts.mint_nft_reward(roll_seed)?;
     }
 
     Ok(())
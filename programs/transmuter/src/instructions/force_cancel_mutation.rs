@@ -0,0 +1,207 @@
+//! Lets the maker clear out pending executions a taker never completed, so the mutation can be destroyed:
+//! 1) Only once the mutation stopped taking new executions (paused or past its end_ts),
+//!    and the execution has been due for a while, so takers get a fair chance to complete first
+//! 2) Vaults are unlocked, so the taker can withdraw again
+//! 3) The use is put back & the execution no longer counts towards the taker's limit
+//! 4) The payment held in escrow is refunded in full - the taker isn't at fault, so no penalty
+//! 5) The receipt goes back to NotStarted
+
+use crate::*;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::Token;
+use gem_bank::program::GemBank;
+
+/// a pending execution has to be due for this long before the maker can cancel it
+pub const FORCE_CANCEL_GRACE_SEC: u64 = 24 * 60 * 60;
+
+#[derive(Accounts)]
+pub struct ForceCancelMutation<'info> {
+    // mutation
+    #[account(has_one = authority)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut, has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role (pays for any missing accounts)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+    // skipping validation to save compute, has_one = auth is enough
+    /// CHECK:
+    pub authority: AccountInfo<'info>,
+
+    // execution
+    #[account(mut,
+        constraint = execution_receipt.to_account_info().owner == program_id,
+        has_one = taker, has_one = mutation)]
+    pub execution_receipt: Box<Account<'info, ExecutionReceipt>>,
+    /// CHECK: gets the refund, has_one check on the receipt enough
+    #[account(mut)]
+    pub taker: AccountInfo<'info>,
+    #[account(init_if_needed, seeds = [
+            b"taker_record".as_ref(),
+            mutation.key().as_ref(),
+            taker.key().as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = 8 + std::mem::size_of::<TakerRecord>())]
+    pub taker_record: Box<Account<'info, TakerRecord>>,
+
+    // taker bank + vault of slot a (every other slot in use is passed via remaining accounts)
+    /// CHECK:
+    pub bank_a: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub vault_a: AccountInfo<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    // refund in spl tokens (might be fake if nothing was paid in tokens)
+    /// CHECK:
+    pub price_mint: AccountInfo<'info>, //checked in validate
+    /// CHECK:
+    #[account(mut)]
+    pub price_escrow: AccountInfo<'info>, //checked in validate
+    /// CHECK:
+    #[account(mut)]
+    pub price_taker_ata: AccountInfo<'info>, //checked in validate
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ForceCancelMutation<'info> {
+    fn price_tokens(&self) -> PriceTokens<'_, 'info> {
+        PriceTokens {
+            transmuter: &self.transmuter,
+            authority: self.authority.clone(),
+            mint: self.price_mint.clone(),
+            escrow: self.price_escrow.clone(),
+            taker: self.taker.clone(),
+            payer: self.admin.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            rent: self.rent.to_account_info(),
+        }
+    }
+
+    /// taker slots other than a are passed as remaining accounts, (bank, vault) for each one in use
+    fn unlock_taker_vaults(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let slots = load_taker_slots(
+            &self.mutation.config,
+            &self.execution_receipt,
+            self.bank_a.clone(),
+            self.vault_a.clone(),
+            &mut remaining_accounts.iter(),
+        )?;
+
+        for slot in slots {
+            set_vault_lock(
+                self.gem_bank.to_account_info(),
+                self.authority.clone(),
+                &self.transmuter,
+                slot.bank,
+                slot.vault,
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// lamports were escrowed on the receipt itself, price tokens in the payment escrow
+    fn refund(&self, lamports: u64, token_amount: u64) -> Result<()> {
+        if lamports > 0 {
+            withdraw_lamports(
+                &self.execution_receipt.to_account_info(),
+                &self.taker,
+                lamports,
+            )?;
+        }
+
+        if token_amount > 0 {
+            let price_tokens = self.price_tokens();
+            price_tokens.transfer(
+                self.price_escrow.clone(),
+                self.price_taker_ata.clone(),
+                self.authority.clone(),
+                self.taker.clone(),
+                token_amount,
+            )?;
+            price_tokens.close_escrow()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ForceCancelMutation<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )?;
+
+        // only used if price tokens are escrowed
+        if self.execution_receipt.escrowed_amount > 0 {
+            let receipt = self.execution_receipt.key();
+            let (payment_escrow, _) = Pubkey::find_program_address(
+                &[b"payment_escrow".as_ref(), receipt.as_ref()],
+                &crate::ID,
+            );
+            assert_keys_eq!(self.price_escrow.key(), payment_escrow, "payment escrow");
+            assert_keys_eq!(
+                self.price_taker_ata.key(),
+                get_associated_token_address(&self.taker.key(), &self.price_mint.key()),
+                "taker's price ata"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ForceCancelMutation<'info>>,
+) -> Result<()> {
+    let now_ts = now_ts()?;
+    require!(
+        !ctx.accounts
+            .mutation
+            .accepts_executions(&ctx.accounts.transmuter, now_ts),
+        ForceCancellationNotAllowed
+    );
+
+    let execution_receipt = &mut ctx.accounts.execution_receipt;
+    require!(execution_receipt.is_pending(), ExecutionNotPending);
+    require!(
+        now_ts
+            >= execution_receipt
+                .mutation_complete_ts
+                .try_add(FORCE_CANCEL_GRACE_SEC)?,
+        ForceCancellationNotAllowed
+    );
+    let (lamports, token_amount) = execution_receipt.release_payment();
+    execution_receipt.record_cancellation();
+
+    // --------------------------------------- uses & refund
+
+    ctx.accounts.taker_record.record_reversal();
+
+    let mutation = &mut ctx.accounts.mutation;
+    mutation.increment_uses()?;
+    mutation.finish_pending_execution()?;
+
+    ctx.accounts.refund(lamports, token_amount)?;
+
+    // --------------------------------------- unlock taker vaults
+
+    ctx.accounts.unlock_taker_vaults(ctx.remaining_accounts)
+}
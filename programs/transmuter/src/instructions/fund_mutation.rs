@@ -0,0 +1,163 @@
+//! Tops up an existing mutation with more uses. Anyone can sponsor this, not just the owner.
+//! Each escrow is funded with uses * amount_per_use, so the ratio between maker tokens is kept.
+//! Minted tokens have nothing to fund. Extra escrows are passed as remaining accounts:
+//! (escrow, source) for every configured slot, in slot order (both unused if minted).
+//! Note that whatever is funded goes back to the owner, not the sponsor, on destroy_mutation.
+
+use crate::*;
+use anchor_lang::solana_program::account_info::next_account_info;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct FundMutation<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut,
+        has_one = transmuter,
+        has_one = token_a_escrow,
+    )] //other 2 escrows conditionally checked in validate
+    pub mutation: Box<Account<'info, Mutation>>,
+
+    // tokens
+    // a
+    #[account(mut)]
+    pub token_a_escrow: Box<Account<'info, TokenAccount>>,
+    /// CHECK:
+    #[account(mut)]
+    pub token_a_source: AccountInfo<'info>, //skip deser coz might be unused
+    // b
+    /// CHECK:
+    #[account(mut)]
+    pub token_b_escrow: AccountInfo<'info>, //skip deser coz might be empty
+    /// CHECK:
+    #[account(mut)]
+    pub token_b_source: AccountInfo<'info>, //skip deser coz might be unused
+    // c
+    /// CHECK:
+    #[account(mut)]
+    pub token_c_escrow: AccountInfo<'info>, //skip deser coz might be empty
+    /// CHECK:
+    #[account(mut)]
+    pub token_c_source: AccountInfo<'info>, //skip deser coz might be unused
+
+    // misc
+    pub sponsor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundMutation<'info> {
+    fn transfer_ctx(
+        &self,
+        token_source: AccountInfo<'info>,
+        token_escrow: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: token_source,
+                to: token_escrow,
+                authority: self.sponsor.to_account_info(),
+            },
+        )
+    }
+
+    fn fund_escrow(
+        &self,
+        source: AccountInfo<'info>,
+        escrow: AccountInfo<'info>,
+        funding: u64,
+    ) -> Result<()> {
+        // minted tokens have nothing to fund
+        if funding == 0 {
+            return Ok(());
+        }
+
+        token::transfer(self.transfer_ctx(source, escrow), funding)
+    }
+}
+
+impl<'info> Validate<'info> for FundMutation<'info> {
+    fn validate(&self) -> Result<()> {
+        if let Some(b_escrow) = self.mutation.token_b_escrow {
+            assert_keys_eq!(self.token_b_escrow.key(), b_escrow, "b escrow");
+        }
+
+        if let Some(c_escrow) = self.mutation.token_c_escrow {
+            assert_keys_eq!(self.token_c_escrow.key(), c_escrow, "c escrow");
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, FundMutation<'info>>,
+    uses: u64,
+) -> Result<()> {
+    let mutation = &mut ctx.accounts.mutation;
+    mutation.add_uses(uses)?;
+
+    // record the extra funding on the config, so that total_funding keeps adding up
+    let config = &mut mutation.config;
+    let funding_a = config.maker_token_a.add_funding(uses)?;
+    let funding_b = match &mut config.maker_token_b {
+        Some(maker_token_b) => maker_token_b.add_funding(uses)?,
+        None => 0,
+    };
+    let funding_c = match &mut config.maker_token_c {
+        Some(maker_token_c) => maker_token_c.add_funding(uses)?,
+        None => 0,
+    };
+    let mut funding_extra = [0; 2];
+    for (i, maker_token) in config.extra_maker_tokens.iter_mut().enumerate() {
+        if let Some(maker_token) = maker_token {
+            funding_extra[i] = maker_token.add_funding(uses)?;
+        }
+    }
+
+    // first escrow
+    let source_a = ctx.accounts.token_a_source.to_account_info();
+    let escrow_a = ctx.accounts.token_a_escrow.to_account_info();
+    ctx.accounts.fund_escrow(source_a, escrow_a, funding_a)?;
+
+    // second escrow
+    let source_b = ctx.accounts.token_b_source.to_account_info();
+    let escrow_b = ctx.accounts.token_b_escrow.to_account_info();
+    ctx.accounts.fund_escrow(source_b, escrow_b, funding_b)?;
+
+    // third escrow
+    let source_c = ctx.accounts.token_c_source.to_account_info();
+    let escrow_c = ctx.accounts.token_c_escrow.to_account_info();
+    ctx.accounts.fund_escrow(source_c, escrow_c, funding_c)?;
+
+    // extra escrows
+    let extra_accounts = &mut ctx.remaining_accounts.iter();
+    for (i, maker_token) in ctx
+        .accounts
+        .mutation
+        .config
+        .extra_maker_tokens
+        .iter()
+        .enumerate()
+    {
+        if maker_token.is_none() {
+            continue;
+        }
+
+        let escrow = next_account_info(extra_accounts)?;
+        let source = next_account_info(extra_accounts)?;
+
+        // minted tokens have no escrow
+        let extra_escrow = match ctx.accounts.mutation.extra_escrows[i] {
+            Some(extra_escrow) => extra_escrow,
+            None => continue,
+        };
+        assert_keys_eq!(escrow.key(), extra_escrow, "extra escrow");
+
+        ctx.accounts
+            .fund_escrow(source.clone(), escrow.clone(), funding_extra[i])?;
+    }
+
+    Ok(())
+}
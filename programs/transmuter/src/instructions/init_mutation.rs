@@ -1,21 +1,21 @@
 use crate::*;
-use anchor_lang::solana_program::program::invoke;
-use anchor_lang::solana_program::system_instruction;
+use anchor_lang::solana_program::account_info::next_account_info;
 use anchor_spl::token::{self, InitializeAccount, Mint, Token, TokenAccount, Transfer};
 use std::io::Write;
-use std::str::FromStr;
-
-const FEE_LAMPORTS: u64 = 100_000_000; // 0.1 SOL per mutation
 
 #[derive(Accounts)]
 #[instruction(bump_auth: u8)]
 pub struct InitMutation<'info> {
     // mutation
-    #[account(has_one = authority, has_one = owner)]
+    #[account(mut, has_one = authority)]
     pub transmuter: Box<Account<'info, Transmuter>>,
     #[account(init, payer = payer, space = 8 + std::mem::size_of::<Mutation>())]
     pub mutation: Box<Account<'info, Mutation>>,
-    pub owner: Signer<'info>,
+    /// owner, or a member with the mutation manager role (funds the escrows,
+    /// only the owner can set revenue splits or mint maker tokens)
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
     /// CHECK:
     #[account(seeds = [transmuter.key().as_ref()], bump = bump_auth)]
     pub authority: AccountInfo<'info>,
@@ -55,12 +55,8 @@ pub struct InitMutation<'info> {
     // misc
     #[account(mut)]
     pub payer: Signer<'info>,
-    /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
-    pub fee_acc: AccountInfo<'info>,
-    /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET_2).unwrap())]
-    pub fee_acc2: AccountInfo<'info>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -77,7 +73,7 @@ impl<'info> InitMutation<'info> {
             Transfer {
                 from: token_source,
                 to: token_escrow,
-                authority: self.owner.to_account_info(),
+                authority: self.admin.to_account_info(),
             },
         )
     }
@@ -93,6 +89,11 @@ impl<'info> InitMutation<'info> {
         maker_token.assert_correct_mint(mint)?;
         maker_token.assert_sufficient_funding(uses)?;
 
+        // minted tokens have nothing to fund
+        if maker_token.is_minted() {
+            return Ok(());
+        }
+
         token::transfer(self.transfer_ctx(source, escrow), maker_token.total_funding)
     }
 
@@ -136,50 +137,66 @@ impl<'info> InitMutation<'info> {
         // init
         anchor_spl::token::initialize_account(self.init_token_account(token_account, mint))
     }
+}
 
-    fn transfer_fee(&self, dest: &AccountInfo<'info>) -> Result<()> {
-        invoke(
-            &system_instruction::transfer(self.payer.key, dest.key, FEE_LAMPORTS / 2),
-            &[
-                self.payer.to_account_info(),
-                dest.clone(),
-                self.system_program.to_account_info(),
-            ],
+impl<'info> Validate<'info> for InitMutation<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
         )
-        .map_err(Into::into)
     }
 }
 
-pub fn handler(
-    ctx: Context<InitMutation>,
+/// escrows for extra maker tokens are passed as remaining accounts,
+/// (escrow, source, mint) for every configured slot, in slot order (escrow & source unused if minted),
+/// followed by the protocol fee recipients
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, InitMutation<'info>>,
     config: MutationConfig,
     uses: u64,
     bump_b: u8,
     bump_c: u8,
     name: String,
+    revenue_splits: Vec<RevenueSplit>,
 ) -> Result<()> {
+    // revenue splits & minted maker tokens decide where value goes, so they're left to the owner
+    if ctx.accounts.admin.key() != ctx.accounts.transmuter.owner {
+        require!(
+            revenue_splits.is_empty() && !config.maker_tokens().any(|t| t.is_minted()),
+            OwnerOnlyMutationConfig
+        );
+    }
+
     let mutation = &mut ctx.accounts.mutation;
 
     mutation.transmuter = ctx.accounts.transmuter.key();
     mutation.config = config;
     mutation.init_uses(uses);
     mutation.config.assert_is_valid()?;
+    ctx.accounts.transmuter.assert_taker_banks_match(&config)?;
     mutation.token_a_escrow = ctx.accounts.token_a_escrow.key();
-    if config.maker_token_b.is_some() {
+    if config.maker_token_b.filter(|t| !t.is_minted()).is_some() {
         mutation.token_b_escrow = Some(ctx.accounts.token_b_escrow.key());
     }
-    if config.maker_token_c.is_some() {
+    if config.maker_token_c.filter(|t| !t.is_minted()).is_some() {
         mutation.token_c_escrow = Some(ctx.accounts.token_c_escrow.key());
     }
     (&mut mutation.name[..]).write_all(name.as_bytes())?;
+    mutation.set_revenue_splits(&revenue_splits)?;
 
     // first escrow
     let mint_a = ctx.accounts.token_a_mint.to_account_info();
     let source_a = ctx.accounts.token_a_source.to_account_info();
     let escrow_a = ctx.accounts.token_a_escrow.to_account_info();
     let maker_token_a = config.maker_token_a;
+    let authority = ctx.accounts.authority.key();
+    maker_token_a.assert_can_mint(&ctx.accounts.token_a_mint, authority)?;
 
-    //fund (created in validator struct)
+    //fund (created in validator struct, stays empty if minted)
     ctx.accounts
         .fund_escrow(mint_a.key(), uses, source_a, escrow_a, maker_token_a)?;
 
@@ -188,10 +205,13 @@ pub fn handler(
         let mint_b = ctx.accounts.token_b_mint.to_account_info();
         let source_b = ctx.accounts.token_b_source.to_account_info();
         let escrow_b = ctx.accounts.token_b_escrow.to_account_info();
+        maker_token_b.assert_can_mint(&ctx.accounts.token_b_mint, authority)?;
 
         // create
-        ctx.accounts
-            .create_and_init_escrow(escrow_b.clone(), mint_b.clone(), bump_b)?;
+        if !maker_token_b.is_minted() {
+            ctx.accounts
+                .create_and_init_escrow(escrow_b.clone(), mint_b.clone(), bump_b)?;
+        }
 
         // fund
         ctx.accounts
@@ -203,19 +223,66 @@ pub fn handler(
         let mint_c = ctx.accounts.token_c_mint.to_account_info();
         let source_c = ctx.accounts.token_c_source.to_account_info();
         let escrow_c = ctx.accounts.token_c_escrow.to_account_info();
+        maker_token_c.assert_can_mint(&ctx.accounts.token_c_mint, authority)?;
 
         // create
-        ctx.accounts
-            .create_and_init_escrow(escrow_c.clone(), mint_c.clone(), bump_c)?;
+        if !maker_token_c.is_minted() {
+            ctx.accounts
+                .create_and_init_escrow(escrow_c.clone(), mint_c.clone(), bump_c)?;
+        }
 
         // fund
         ctx.accounts
             .fund_escrow(mint_c.key(), uses, source_c, escrow_c, maker_token_c)?;
     }
 
-    //collect transmuter fee
-    ctx.accounts.transfer_fee(&ctx.accounts.fee_acc)?;
-    ctx.accounts.transfer_fee(&ctx.accounts.fee_acc2)?;
+    // fund extra escrows
+    let extra_accounts = &mut ctx.remaining_accounts.iter();
+    for (i, maker_token) in config.extra_maker_tokens.iter().enumerate() {
+        if let Some(maker_token) = *maker_token {
+            let escrow = next_account_info(extra_accounts)?;
+            let source = next_account_info(extra_accounts)?;
+            let mint = next_account_info(extra_accounts)?;
+            let mint_acc: Account<Mint> = Account::try_from(mint)?;
+            maker_token.assert_can_mint(&mint_acc, authority)?;
+
+            // create (fails unless escrow is the expected pda)
+            if !maker_token.is_minted() {
+                let (_, bump) = Pubkey::find_program_address(
+                    &[
+                        b"escrow".as_ref(),
+                        ctx.accounts.mutation.key().as_ref(),
+                        mint.key().as_ref(),
+                    ],
+                    ctx.program_id,
+                );
+                ctx.accounts
+                    .create_and_init_escrow(escrow.clone(), mint.clone(), bump)?;
+                ctx.accounts.mutation.extra_escrows[i] = Some(escrow.key());
+            }
+
+            // fund
+            ctx.accounts.fund_escrow(
+                mint.key(),
+                uses,
+                source.clone(),
+                escrow.clone(),
+                maker_token,
+            )?;
+        }
+    }
+
+    ctx.accounts.transmuter.record_mutation_created()?;
+
+    //collect protocol fee
+    let protocol_config = &ctx.accounts.protocol_config;
+    protocol_config.collect_fee(
+        protocol_config.mutation_fee_lamports,
+        ctx.accounts.transmuter.owner,
+        &ctx.accounts.payer.to_account_info(),
+        extra_accounts,
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     Ok(())
 }
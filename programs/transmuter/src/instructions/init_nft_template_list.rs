@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct InitNftTemplateList<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut, has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+
+    // created by the client beforehand (can be larger than what can be allocated via cpi),
+    // owned by this program and sized NFT_TEMPLATE_LIST_HEADER_LEN + capacity * NFT_TEMPLATE_LEN
+    #[account(zero)]
+    pub nft_template_list: Box<Account<'info, NftTemplateList>>,
+}
+
+impl<'info> Validate<'info> for InitNftTemplateList<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// each mutation only gets a single list, so that takers can't pick between lists
+#[access_control(ctx.accounts.validate())]
+pub fn handler(
+    ctx: Context<InitNftTemplateList>,
+    symbol: String,
+    seller_fee_basis_points: u16,
+) -> Result<()> {
+    let mutation = &mut ctx.accounts.mutation;
+    require!(mutation.nft_template_list.is_none(), InvalidNftReward);
+    require!(
+        seller_fee_basis_points as u64 <= BPS_DENOMINATOR,
+        InvalidNftReward
+    );
+
+    let nft_template_list = &mut ctx.accounts.nft_template_list;
+    let data_len = nft_template_list.to_account_info().data_len();
+
+    nft_template_list.mutation = mutation.key();
+    nft_template_list.set_symbol(&symbol)?;
+    nft_template_list.seller_fee_basis_points = seller_fee_basis_points;
+    nft_template_list.capacity = NftTemplateList::capacity_for(data_len);
+
+    mutation.nft_template_list = Some(nft_template_list.key());
+
+    Ok(())
+}
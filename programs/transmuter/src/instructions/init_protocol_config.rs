@@ -0,0 +1,45 @@
+use crate::program::TransmuterV0;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct InitProtocolConfig<'info> {
+    #[account(init, seeds = [b"protocol_config".as_ref()],
+        bump,
+        payer = admin,
+        space = 8 + std::mem::size_of::<ProtocolConfig>())]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // only the upgrade authority can claim the admin role
+    pub program: Program<'info, TransmuterV0>,
+    pub program_data: Box<Account<'info, ProgramData>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for InitProtocolConfig<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.program_data.key(),
+            self.program.programdata_address()?.unwrap_or_default(),
+            "program data"
+        );
+        require!(
+            self.program_data.upgrade_authority_address == Some(self.admin.key()),
+            NotUpgradeAuthority
+        );
+        Ok(())
+    }
+}
+
+/// starts out without any fees, set them with update_protocol_config
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<InitProtocolConfig>) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.admin = ctx.accounts.admin.key();
+
+    Ok(())
+}
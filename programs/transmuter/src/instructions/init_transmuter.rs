@@ -1,12 +1,5 @@
 use crate::*;
-use anchor_lang::solana_program::program::invoke;
-use anchor_lang::solana_program::system_instruction;
-use gem_bank::{self, cpi::accounts::InitBank, program::GemBank};
-use std::str::FromStr;
-
-pub const FEE_WALLET: &str = "2U9sG2BRF8TbUjor1Dms8rRRxVqAjJSktZYCwhXFNYCC"; //6th
-pub const FEE_WALLET_2: &str = "BkRfbyZWsDwQWvJTibjJ1wuvBuFsw8HvfgHEEDCXHDRQ";
-const FEE_LAMPORTS: u64 = 2_500_000_000; // 2.5 SOL per transmuter
+use gem_bank::{self, cpi::accounts::InitBank, program::GemBank, state::Bank};
 
 #[derive(Accounts)]
 pub struct InitTransmuter<'info> {
@@ -18,24 +11,23 @@ pub struct InitTransmuter<'info> {
     #[account(seeds = [transmuter.key().as_ref()], bump)]
     pub authority: AccountInfo<'info>,
 
-    // taker banks
+    // taker banks (signers if created, fake if skipped)
+    /// CHECK:
     #[account(mut)]
-    pub bank_a: Signer<'info>,
+    pub bank_a: AccountInfo<'info>,
+    /// CHECK:
     #[account(mut)]
-    pub bank_b: Signer<'info>,
+    pub bank_b: AccountInfo<'info>,
+    /// CHECK:
     #[account(mut)]
-    pub bank_c: Signer<'info>,
+    pub bank_c: AccountInfo<'info>,
     pub gem_bank: Program<'info, GemBank>,
 
     // misc
     #[account(mut)]
     pub payer: Signer<'info>,
-    /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
-    pub fee_acc: AccountInfo<'info>,
-    /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET_2).unwrap())]
-    pub fee_acc2: AccountInfo<'info>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -56,23 +48,40 @@ impl<'info> InitTransmuter<'info> {
         )
     }
 
-    fn transfer_fee(&self, dest: &AccountInfo<'info>) -> Result<()> {
-        invoke(
-            &system_instruction::transfer(self.payer.key, dest.key, FEE_LAMPORTS / 2),
-            &[
-                self.payer.to_account_info(),
-                dest.clone(),
-                self.system_program.to_account_info(),
-            ],
-        )
-        .map_err(Into::into)
+    /// returns the key to store on the transmuter for this bank
+    fn setup_bank(
+        &self,
+        bank: AccountInfo<'info>,
+        setup: BankSetup,
+        full_seeds: &[&[u8]],
+    ) -> Result<Pubkey> {
+        match setup {
+            BankSetup::Create => {
+                gem_bank::cpi::init_bank(
+                    self.init_bank_ctx(bank.clone()).with_signer(&[full_seeds]),
+                )?;
+                Ok(bank.key())
+            }
+            BankSetup::Import => {
+                let bank_acc: Account<Bank> = Account::try_from(&bank)?;
+                assert_keys_eq!(bank_acc.bank_manager, self.authority.key(), "bank manager");
+                Ok(bank.key())
+            }
+            BankSetup::Skip => Ok(Pubkey::default()),
+        }
     }
 }
 
+/// protocol fee recipients are passed as remaining accounts
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, InitTransmuter<'info>>,
     bump_auth: u8,
+    bank_a_setup: BankSetup,
+    bank_b_setup: BankSetup,
+    bank_c_setup: BankSetup,
 ) -> Result<()> {
+    require!(bank_a_setup != BankSetup::Skip, InvalidBankSetup);
+
     let transmuter = &mut ctx.accounts.transmuter;
     let key = transmuter.key();
 
@@ -85,38 +94,35 @@ pub fn handler<'a, 'b, 'c, 'info>(
 
     let full_seeds = [key.as_ref(), &[bump_auth]];
 
-    // init first bank
+    // set up banks
     let bank_a = ctx.accounts.bank_a.to_account_info();
-    transmuter.bank_a = bank_a.key();
-    gem_bank::cpi::init_bank(
-        ctx.accounts
-            .init_bank_ctx(bank_a)
-            .with_signer(&[&full_seeds]),
-    )?;
-
-    // init second bank
-    let transmuter = &mut ctx.accounts.transmuter;
+    let bank_a = ctx.accounts.setup_bank(bank_a, bank_a_setup, &full_seeds)?;
     let bank_b = ctx.accounts.bank_b.to_account_info();
-    transmuter.bank_b = bank_b.key();
-    gem_bank::cpi::init_bank(
-        ctx.accounts
-            .init_bank_ctx(bank_b)
-            .with_signer(&[&full_seeds]),
-    )?;
+    let bank_b = ctx.accounts.setup_bank(bank_b, bank_b_setup, &full_seeds)?;
+    let bank_c = ctx.accounts.bank_c.to_account_info();
+    let bank_c = ctx.accounts.setup_bank(bank_c, bank_c_setup, &full_seeds)?;
+
+    // skipped banks are all Pubkey::default(), anything else has to be unique
+    require!(bank_a != bank_b && bank_a != bank_c, InvalidBankSetup);
+    require!(
+        bank_b != bank_c || bank_b == Pubkey::default(),
+        InvalidBankSetup
+    );
 
-    // init third bank
     let transmuter = &mut ctx.accounts.transmuter;
-    let bank_c = ctx.accounts.bank_c.to_account_info();
-    transmuter.bank_c = bank_c.key();
-    gem_bank::cpi::init_bank(
-        ctx.accounts
-            .init_bank_ctx(bank_c)
-            .with_signer(&[&full_seeds]),
-    )?;
+    transmuter.bank_a = bank_a;
+    transmuter.bank_b = bank_b;
+    transmuter.bank_c = bank_c;
 
-    //collect transmuter fee
-    ctx.accounts.transfer_fee(&ctx.accounts.fee_acc)?;
-    ctx.accounts.transfer_fee(&ctx.accounts.fee_acc2)?;
+    //collect protocol fee
+    let protocol_config = &ctx.accounts.protocol_config;
+    protocol_config.collect_fee(
+        protocol_config.transmuter_fee_lamports,
+        ctx.accounts.owner.key(),
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.remaining_accounts.iter(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,41 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateExecutionReceipt<'info> {
+    // mutation
+    #[account(mut)]
+    pub mutation: Box<Account<'info, Mutation>>,
+
+    // execution
+    /// CHECK: still in the legacy layout, read in the handler
+    #[account(mut, constraint = *execution_receipt.owner == crate::ID)]
+    pub execution_receipt: AccountInfo<'info>,
+
+    // misc
+    /// anyone can migrate, they just pay for the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// pending receipts are counted towards the mutation's pending_executions
+pub fn handler(ctx: Context<MigrateExecutionReceipt>) -> Result<()> {
+    let legacy: LegacyExecutionReceipt = read_legacy(
+        &ctx.accounts.execution_receipt,
+        ExecutionReceipt::discriminator(),
+    )?;
+    assert_keys_eq!(legacy.mutation, ctx.accounts.mutation.key(), "mutation");
+    let execution_receipt = ExecutionReceipt::from(legacy);
+
+    if execution_receipt.is_pending() {
+        ctx.accounts.mutation.start_pending_execution()?;
+    }
+
+    write_migrated(
+        &ctx.accounts.execution_receipt,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &execution_receipt,
+    )
+}
@@ -0,0 +1,39 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateMutation<'info> {
+    // mutation
+    #[account(mut)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    /// CHECK: still in the legacy layout, read in the handler
+    #[account(mut, constraint = *mutation.owner == crate::ID)]
+    pub mutation: AccountInfo<'info>,
+
+    // misc
+    /// anyone can migrate, they just pay for the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// counts the mutation towards the transmuter's mutation_count,
+/// has to run before any of the mutation's receipts can be migrated
+pub fn handler(ctx: Context<MigrateMutation>) -> Result<()> {
+    let legacy: LegacyMutation = read_legacy(&ctx.accounts.mutation, Mutation::discriminator())?;
+    assert_keys_eq!(
+        legacy.transmuter,
+        ctx.accounts.transmuter.key(),
+        "transmuter"
+    );
+    let mutation = Mutation::from(legacy);
+
+    ctx.accounts.transmuter.record_mutation_migrated()?;
+
+    write_migrated(
+        &ctx.accounts.mutation,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &mutation,
+    )
+}
@@ -0,0 +1,35 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateTransmuter<'info> {
+    /// CHECK: still in the legacy layout, read in the handler
+    #[account(mut, constraint = *transmuter.owner == crate::ID)]
+    pub transmuter: AccountInfo<'info>,
+    /// has to vouch for the number of legacy mutations
+    pub owner: Signer<'info>,
+
+    // misc
+    /// pays for the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// has to run before any of the transmuter's mutations can be migrated.
+/// The owner declares how many legacy mutations the transmuter has (there's no enumerating them on chain),
+/// the transmuter can't be closed until that many are migrated & destroyed
+pub fn handler(ctx: Context<MigrateTransmuter>, legacy_mutation_count: u32) -> Result<()> {
+    let legacy: LegacyTransmuter =
+        read_legacy(&ctx.accounts.transmuter, Transmuter::discriminator())?;
+    assert_keys_eq!(legacy.owner, ctx.accounts.owner.key(), "owner");
+    let mut transmuter = Transmuter::from(legacy);
+    transmuter.unmigrated_mutations = legacy_mutation_count;
+
+    write_migrated(
+        &ctx.accounts.transmuter,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &transmuter,
+    )
+}
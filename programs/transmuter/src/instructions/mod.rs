@@ -1,17 +1,61 @@
+pub mod accept_transmuter_owner;
+pub mod add_nft_templates;
+pub mod add_transmuter_bank;
 pub mod bank_instructions;
+pub mod cancel_mutation;
+pub mod cancel_transmuter_owner;
+pub mod close_execution_receipt;
+pub mod close_transmuter;
 pub mod destroy_mutation;
 pub mod execute_mutation;
+pub mod force_cancel_mutation;
+pub mod fund_mutation;
 pub mod init_mutation;
+pub mod init_nft_template_list;
+pub mod init_protocol_config;
 pub mod init_transmuter;
+pub mod migrate_execution_receipt;
+pub mod migrate_mutation;
+pub mod migrate_transmuter;
 pub mod reverse_mutation;
+pub mod revoke_transmuter_role;
+pub mod set_metadata_template;
+pub mod set_mutation_paused;
+pub mod set_taker_list;
+pub mod set_transmuter_paused;
+pub mod set_transmuter_role;
+pub mod update_mutation;
+pub mod update_protocol_config;
 pub mod update_transmuter;
 
+pub use accept_transmuter_owner::*;
+pub use add_nft_templates::*;
+pub use add_transmuter_bank::*;
 pub use bank_instructions::*;
+pub use cancel_mutation::*;
+pub use cancel_transmuter_owner::*;
+pub use close_execution_receipt::*;
+pub use close_transmuter::*;
 pub use destroy_mutation::*;
 pub use execute_mutation::*;
+pub use force_cancel_mutation::*;
+pub use fund_mutation::*;
 pub use init_mutation::*;
+pub use init_nft_template_list::*;
+pub use init_protocol_config::*;
 pub use init_transmuter::*;
+pub use migrate_execution_receipt::*;
+pub use migrate_mutation::*;
+pub use migrate_transmuter::*;
 pub use reverse_mutation::*;
+pub use revoke_transmuter_role::*;
+pub use set_metadata_template::*;
+pub use set_mutation_paused::*;
+pub use set_taker_list::*;
+pub use set_transmuter_paused::*;
+pub use set_transmuter_role::*;
+pub use update_mutation::*;
+pub use update_protocol_config::*;
 pub use update_transmuter::*;
 
 // have to duplicate or this won't show up in IDL
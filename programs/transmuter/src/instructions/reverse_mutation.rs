@@ -8,93 +8,85 @@ pub fn handler<'a, 'b, 'c, 'info>(
         return Err(error!(ErrorCode::MutationNotReversible));
     }
 
+    let (slots, split_accounts) = ctx.accounts.load_slots(ctx.remaining_accounts)?;
+    let reversal = ctx.accounts.mutation.config.reversal;
+    let now_ts = now_ts()?;
+
     let execution_receipt = &mut ctx.accounts.execution_receipt;
     if !execution_receipt.is_complete() {
         return Err(error!(ErrorCode::MutationNotComplete));
     }
+    let mutation_complete_ts = execution_receipt.mutation_complete_ts;
+    reversal.assert_within_window(mutation_complete_ts, now_ts)?;
+    reversal.assert_can_reverse_again(execution_receipt.reversal_count)?;
+    execution_receipt.record_reversal()?;
     execution_receipt.mark_not_started();
 
     // --------------------------------------- uses & payment
 
+    ctx.accounts.taker_record.record_reversal();
+
     let mutation = &mut ctx.accounts.mutation;
     mutation.increment_uses()?;
 
-    let price = mutation.config.price.reversal_price_lamports;
+    // refunds come out of the owner's pocket, fees get split like any other payment
+    let price = reversal.current_price(
+        mutation.config.price.reversal_price_lamports,
+        mutation_complete_ts,
+        now_ts,
+    )?;
+    let token_price = reversal.current_price(
+        mutation.config.price.reversal_price_amount,
+        mutation_complete_ts,
+        now_ts,
+    )?;
     if price < 0 {
         ctx.accounts.make_payment(
             ctx.accounts.owner.to_account_info(),
             ctx.accounts.taker.to_account_info(),
             price.abs() as u64,
         )?;
-    } else if price > 0 {
-        ctx.accounts.make_payment(
-            ctx.accounts.taker.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-            price as u64,
-        )?;
     }
-
-    // --------------------------------------- unlock taker vaults
-
-    let config = ctx.accounts.mutation.config;
-
-    // first bank
-    ctx.accounts.perform_vault_action(
-        ctx.accounts.bank_a.to_account_info(),
-        ctx.accounts.vault_a.to_account_info(),
-        ctx.accounts.mutation.config.taker_token_a,
-        false,
+    if token_price < 0 {
+        ctx.accounts
+            .refund_token_payment(token_price.abs() as u64)?;
+    }
+    ctx.accounts.pay_maker(
+        price.max(0) as u64,
+        token_price.max(0) as u64,
+        split_accounts,
         false,
     )?;
 
-    // second bank
-    if let Some(taker_token_b) = config.taker_token_b {
-        ctx.accounts.perform_vault_action(
-            ctx.accounts.bank_b.to_account_info(),
-            ctx.accounts.vault_b.to_account_info(),
-            taker_token_b,
-            false,
-            false,
-        )?;
-    }
+    // --------------------------------------- unlock taker vaults
+    // (reversible mutations only ever Lock, so there's never anything to burn)
 
-    // third bank
-    if let Some(taker_token_c) = config.taker_token_c {
+    for slot in slots.taker.iter() {
         ctx.accounts.perform_vault_action(
-            ctx.accounts.bank_c.to_account_info(),
-            ctx.accounts.vault_c.to_account_info(),
-            taker_token_c,
+            slot.bank.clone(),
+            slot.vault.clone(),
+            slot.config,
             false,
             false,
+            &VaultGems::default(),
         )?;
     }
 
     // --------------------------------------- move back tokens
 
-    // first token
-    ctx.accounts.perform_token_transfer(
-        ctx.accounts.token_a_escrow.to_account_info(),
-        ctx.accounts.token_a_taker_ata.to_account_info(),
-        config.maker_token_a,
-        true,
-    )?;
-
-    // second token
-    if let Some(maker_token_b) = config.maker_token_b {
-        ctx.accounts.perform_token_transfer(
-            ctx.accounts.token_b_escrow.to_account_info(),
-            ctx.accounts.token_b_taker_ata.to_account_info(),
-            maker_token_b,
-            true,
-        )?;
-    }
+    // loot mutations only move the rolled slot
+    let config = ctx.accounts.mutation.config;
+    let loot_outcome = ctx.accounts.execution_receipt.loot_outcome;
 
-    // third token
-    if let Some(maker_token_c) = config.maker_token_c {
+    for slot in slots.maker.iter() {
+        if !config.hands_out_maker_slot(slot.index, loot_outcome) {
+            continue;
+        }
         ctx.accounts.perform_token_transfer(
-            ctx.accounts.token_c_escrow.to_account_info(),
-            ctx.accounts.token_c_taker_ata.to_account_info(),
-            maker_token_c,
+            slot.escrow.clone(),
+            slot.taker_ata.clone(),
+            slot.mint.clone(),
+            slot.config,
             true,
         )?;
     }
@@ -0,0 +1,19 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RevokeTransmuterRole<'info> {
+    // transmuter
+    #[account(has_one = owner)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // role
+    #[account(mut, close = owner, has_one = transmuter)]
+    pub transmuter_role: Box<Account<'info, TransmuterRole>>,
+}
+
+/// all of the member's roles are gone, rent goes back to the owner
+pub fn handler(_ctx: Context<RevokeTransmuterRole>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMetadataTemplate<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+
+    // template can be overwritten at any point, hence init_if_needed
+    #[account(init_if_needed, seeds = [
+            b"metadata_template".as_ref(),
+            mutation.key().as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = METADATA_TEMPLATE_SPACE)]
+    pub metadata_template: Box<Account<'info, MetadataTemplate>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for SetMetadataTemplate<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// for the template to be usable, the transmuter authority has to be the update authority of the NFTs
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<SetMetadataTemplate>, name: Option<String>, uri: String) -> Result<()> {
+    let metadata_template = &mut ctx.accounts.metadata_template;
+
+    metadata_template.mutation = ctx.accounts.mutation.key();
+    metadata_template.name = name;
+    metadata_template.uri = uri;
+    metadata_template.assert_is_valid()?;
+
+    Ok(())
+}
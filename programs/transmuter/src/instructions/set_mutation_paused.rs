@@ -0,0 +1,34 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetMutationPaused<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut, has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+}
+
+impl<'info> Validate<'info> for SetMutationPaused<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// only stops new executions - pending ones can still be completed / reversed
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<SetMutationPaused>, paused: bool) -> Result<()> {
+    let mutation = &mut ctx.accounts.mutation;
+
+    mutation.paused = paused;
+
+    Ok(())
+}
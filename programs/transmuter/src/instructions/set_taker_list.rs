@@ -0,0 +1,34 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetTakerList<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut, has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+}
+
+impl<'info> Validate<'info> for SetTakerList<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// takes effect for executions started from now on - pending ones aren't affected
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<SetTakerList>, taker_list: TakerList) -> Result<()> {
+    let mutation = &mut ctx.accounts.mutation;
+
+    mutation.taker_list = taker_list;
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetTransmuterPaused<'info> {
+    #[account(mut, has_one = owner)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    pub owner: Signer<'info>,
+}
+
+/// emergency switch for all of the transmuter's mutations at once
+/// only stops new executions - pending ones can still be completed / reversed
+pub fn handler(ctx: Context<SetTransmuterPaused>, paused: bool) -> Result<()> {
+    let transmuter = &mut ctx.accounts.transmuter;
+
+    transmuter.paused = paused;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct SetTransmuterRole<'info> {
+    // transmuter
+    #[account(has_one = owner)]
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // role
+    #[account(init_if_needed, seeds = [
+            b"role".as_ref(),
+            transmuter.key().as_ref(),
+            member.as_ref(),
+        ],
+        bump,
+        payer = owner,
+        space = 8 + std::mem::size_of::<TransmuterRole>())]
+    pub transmuter_role: Box<Account<'info, TransmuterRole>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+/// grants / updates a member's roles, only the owner can hand these out
+pub fn handler(
+    ctx: Context<SetTransmuterRole>,
+    member: Pubkey,
+    curator: bool,
+    mutation_manager: bool,
+) -> Result<()> {
+    let transmuter_role = &mut ctx.accounts.transmuter_role;
+
+    transmuter_role.transmuter = ctx.accounts.transmuter.key();
+    transmuter_role.member = member;
+    transmuter_role.curator = curator;
+    transmuter_role.mutation_manager = mutation_manager;
+    transmuter_role.role_epoch = ctx.accounts.transmuter.role_epoch;
+
+    Ok(())
+}
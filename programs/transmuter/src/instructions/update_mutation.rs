@@ -0,0 +1,44 @@
+use crate::*;
+use std::io::Write;
+
+#[derive(Accounts)]
+pub struct UpdateMutation<'info> {
+    // mutation
+    pub transmuter: Box<Account<'info, Transmuter>>,
+    #[account(mut, has_one = transmuter)]
+    pub mutation: Box<Account<'info, Mutation>>,
+    /// owner, or a member with the mutation manager role
+    pub admin: Signer<'info>,
+    /// CHECK: checked in validate, fake if admin is the owner
+    pub admin_role: AccountInfo<'info>,
+}
+
+impl<'info> Validate<'info> for UpdateMutation<'info> {
+    fn validate(&self) -> Result<()> {
+        TransmuterRole::assert_admin_has_role(
+            &self.transmuter,
+            self.admin.key(),
+            &self.admin_role,
+            Role::MutationManager,
+        )
+    }
+}
+
+/// price & duration take effect immediately, including for executions that can still be reversed
+/// (pending ones keep the completion time they were started with)
+#[access_control(ctx.accounts.validate())]
+pub fn handler(
+    ctx: Context<UpdateMutation>,
+    price: PriceConfig,
+    mutation_duration_sec: u64,
+    name: String,
+) -> Result<()> {
+    let mutation = &mut ctx.accounts.mutation;
+
+    mutation.try_update(price, mutation_duration_sec)?;
+
+    mutation.name = [0; 32];
+    (&mut mutation.name[..]).write_all(name.as_bytes())?;
+
+    Ok(())
+}
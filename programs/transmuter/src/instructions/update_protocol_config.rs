@@ -0,0 +1,31 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(mut, seeds = [b"protocol_config".as_ref()], bump, has_one = admin)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub admin: Signer<'info>,
+}
+
+/// replaces all fee settings at once, fee_recipients have to add up to 100% (or be empty)
+pub fn handler(
+    ctx: Context<UpdateProtocolConfig>,
+    new_admin: Pubkey,
+    transmuter_fee_lamports: u64,
+    mutation_fee_lamports: u64,
+    fee_recipients: Vec<RevenueSplit>,
+    fee_exempt: Vec<Pubkey>,
+    execution_fee_recipient: Pubkey,
+    execution_fee_bps: u16,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.admin = new_admin;
+    protocol_config.transmuter_fee_lamports = transmuter_fee_lamports;
+    protocol_config.mutation_fee_lamports = mutation_fee_lamports;
+    protocol_config.set_fee_recipients(&fee_recipients)?;
+    protocol_config.set_fee_exempt(&fee_exempt)?;
+    protocol_config.set_execution_fee(execution_fee_recipient, execution_fee_bps)?;
+
+    Ok(())
+}
@@ -9,10 +9,12 @@ pub struct UpdateTransmuter<'info> {
     pub owner: Signer<'info>,
 }
 
+/// only proposes the new owner, who then has to sign accept_transmuter_owner
+/// (a typo'd key would otherwise lock the owner out of the banks and every mutation)
 pub fn handler(ctx: Context<UpdateTransmuter>, new_owner: Pubkey) -> Result<()> {
     let transmuter = &mut ctx.accounts.transmuter;
 
-    transmuter.owner = new_owner;
+    transmuter.pending_owner = Some(new_owner);
 
     Ok(())
 }
@@ -20,14 +20,53 @@ declare_id!("muto7o7vvfXcvpy5Qgjtaog7GRhtr9Zpzn7PSCmmF8Z");
 pub mod transmuter_v0 {
     use super::*;
 
+    // --------------------------------------- protocol
+
+    pub fn init_protocol_config(ctx: Context<InitProtocolConfig>) -> Result<()> {
+        msg!("init protocol config");
+        instructions::init_protocol_config::handler(ctx)
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        new_admin: Pubkey,
+        transmuter_fee_lamports: u64,
+        mutation_fee_lamports: u64,
+        fee_recipients: Vec<RevenueSplit>,
+        fee_exempt: Vec<Pubkey>,
+        execution_fee_recipient: Pubkey,
+        execution_fee_bps: u16,
+    ) -> Result<()> {
+        msg!("update protocol config");
+        instructions::update_protocol_config::handler(
+            ctx,
+            new_admin,
+            transmuter_fee_lamports,
+            mutation_fee_lamports,
+            fee_recipients,
+            fee_exempt,
+            execution_fee_recipient,
+            execution_fee_bps,
+        )
+    }
+
     // --------------------------------------- maker (transmuter)
 
     pub fn init_transmuter<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, InitTransmuter<'info>>,
         bump_auth: u8,
+        bank_a_setup: BankSetup,
+        bank_b_setup: BankSetup,
+        bank_c_setup: BankSetup,
     ) -> Result<()> {
         msg!("init new transmuter");
-        instructions::init_transmuter::handler(ctx, bump_auth)
+        instructions::init_transmuter::handler(
+            ctx,
+            bump_auth,
+            bank_a_setup,
+            bank_b_setup,
+            bank_c_setup,
+        )
     }
 
     pub fn update_transmuter(ctx: Context<UpdateTransmuter>, new_owner: Pubkey) -> Result<()> {
@@ -35,6 +74,50 @@ pub mod transmuter_v0 {
         instructions::update_transmuter::handler(ctx, new_owner)
     }
 
+    pub fn accept_transmuter_owner(ctx: Context<AcceptTransmuterOwner>) -> Result<()> {
+        msg!("accept transmuter owner");
+        instructions::accept_transmuter_owner::handler(ctx)
+    }
+
+    pub fn cancel_transmuter_owner(ctx: Context<CancelTransmuterOwner>) -> Result<()> {
+        msg!("cancel transmuter owner");
+        instructions::cancel_transmuter_owner::handler(ctx)
+    }
+
+    pub fn set_transmuter_paused(ctx: Context<SetTransmuterPaused>, paused: bool) -> Result<()> {
+        msg!("set transmuter paused");
+        instructions::set_transmuter_paused::handler(ctx, paused)
+    }
+
+    pub fn set_transmuter_role(
+        ctx: Context<SetTransmuterRole>,
+        member: Pubkey,
+        curator: bool,
+        mutation_manager: bool,
+    ) -> Result<()> {
+        msg!("set transmuter role");
+        instructions::set_transmuter_role::handler(ctx, member, curator, mutation_manager)
+    }
+
+    pub fn revoke_transmuter_role(ctx: Context<RevokeTransmuterRole>) -> Result<()> {
+        msg!("revoke transmuter role");
+        instructions::revoke_transmuter_role::handler(ctx)
+    }
+
+    pub fn close_transmuter<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CloseTransmuter<'info>>,
+        _bump_auth: u8,
+        new_bank_manager: Option<Pubkey>,
+    ) -> Result<()> {
+        msg!("close transmuter");
+        instructions::close_transmuter::handler(ctx, new_bank_manager)
+    }
+
+    pub fn add_transmuter_bank(ctx: Context<AddTransmuterBank>, _bump_auth: u8) -> Result<()> {
+        msg!("add transmuter bank");
+        instructions::add_transmuter_bank::handler(ctx)
+    }
+
     pub fn add_to_bank_whitelist(
         ctx: Context<AddToBankWhitelist>,
         _bump_auth: u8,
@@ -64,37 +147,116 @@ pub mod transmuter_v0 {
 
     // --------------------------------------- maker (mutation)
 
-    pub fn init_mutation(
-        ctx: Context<InitMutation>,
+    pub fn init_mutation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, InitMutation<'info>>,
         _bump_auth: u8,
         bump_b: u8,
         bump_c: u8,
         config: MutationConfig,
         uses: u64,
         name: String,
+        revenue_splits: Vec<RevenueSplit>,
     ) -> Result<()> {
         msg!("init new mutation");
-        instructions::init_mutation::handler(ctx, config, uses, bump_b, bump_c, name)
+        instructions::init_mutation::handler(
+            ctx,
+            config,
+            uses,
+            bump_b,
+            bump_c,
+            name,
+            revenue_splits,
+        )
     }
 
-    pub fn destroy_mutation(ctx: Context<DestroyMutation>, _bump_auth: u8) -> Result<()> {
+    pub fn destroy_mutation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DestroyMutation<'info>>,
+        _bump_auth: u8,
+    ) -> Result<()> {
         msg!("destroy mutation");
         instructions::destroy_mutation::handler(ctx)
     }
 
+    pub fn fund_mutation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FundMutation<'info>>,
+        uses: u64,
+    ) -> Result<()> {
+        msg!("fund mutation");
+        instructions::fund_mutation::handler(ctx, uses)
+    }
+
+    pub fn update_mutation(
+        ctx: Context<UpdateMutation>,
+        price: PriceConfig,
+        mutation_duration_sec: u64,
+        name: String,
+    ) -> Result<()> {
+        msg!("update mutation");
+        instructions::update_mutation::handler(ctx, price, mutation_duration_sec, name)
+    }
+
+    pub fn set_mutation_paused(ctx: Context<SetMutationPaused>, paused: bool) -> Result<()> {
+        msg!("set mutation paused");
+        instructions::set_mutation_paused::handler(ctx, paused)
+    }
+
+    pub fn set_taker_list(ctx: Context<SetTakerList>, taker_list: TakerList) -> Result<()> {
+        msg!("set taker list");
+        instructions::set_taker_list::handler(ctx, taker_list)
+    }
+
+    pub fn set_metadata_template(
+        ctx: Context<SetMetadataTemplate>,
+        name: Option<String>,
+        uri: String,
+    ) -> Result<()> {
+        msg!("set metadata template");
+        instructions::set_metadata_template::handler(ctx, name, uri)
+    }
+
+    pub fn init_nft_template_list(
+        ctx: Context<InitNftTemplateList>,
+        symbol: String,
+        seller_fee_basis_points: u16,
+    ) -> Result<()> {
+        msg!("init nft template list");
+        instructions::init_nft_template_list::handler(ctx, symbol, seller_fee_basis_points)
+    }
+
+    pub fn force_cancel_mutation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ForceCancelMutation<'info>>,
+    ) -> Result<()> {
+        msg!("force cancel mutation");
+        instructions::force_cancel_mutation::handler(ctx)
+    }
+
+    pub fn add_nft_templates(
+        ctx: Context<AddNftTemplates>,
+        templates: Vec<NftTemplate>,
+    ) -> Result<()> {
+        msg!("add nft templates");
+        instructions::add_nft_templates::handler(ctx, templates)
+    }
+
     // --------------------------------------- taker
     // deposits / withdrawals are done by hitting bank program directly
 
-    pub fn init_taker_vault(ctx: Context<InitTakerVault>, bump_creator: u8) -> Result<()> {
+    pub fn init_taker_vault(
+        ctx: Context<InitTakerVault>,
+        bump_creator: u8,
+        nonce: u32,
+    ) -> Result<()> {
         msg!("init taker vault");
-        instructions::init_vault::handler(ctx, bump_creator)
+        instructions::init_vault::handler(ctx, bump_creator, nonce)
     }
 
     pub fn execute_mutation<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ExecuteMutation<'info>>,
+        taker_proof: Option<TakerProof>,
+        gem_bumps: Vec<GemBoxBumps>,
     ) -> Result<()> {
         // msg!("execute mutation"); //save compute
-        instructions::execute_mutation::handler(ctx)
+        instructions::execute_mutation::handler(ctx, taker_proof, gem_bumps)
     }
 
     pub fn reverse_mutation<'a, 'b, 'c, 'info>(
@@ -103,4 +265,38 @@ pub mod transmuter_v0 {
         // msg!("reverse mutation"); //save compute
         instructions::reverse_mutation::handler(ctx)
     }
+
+    pub fn cancel_mutation<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ExecuteMutation<'info>>,
+    ) -> Result<()> {
+        // msg!("cancel mutation"); //save compute
+        instructions::cancel_mutation::handler(ctx)
+    }
+
+    pub fn close_execution_receipt(ctx: Context<CloseExecutionReceipt>) -> Result<()> {
+        msg!("close execution receipt");
+        instructions::close_execution_receipt::handler(ctx)
+    }
+
+    // --------------------------------------- migrations
+    // accounts created before the first upgrade have to be migrated to the latest layout,
+    // in order: transmuter, then its mutations, then their receipts
+
+    pub fn migrate_transmuter(
+        ctx: Context<MigrateTransmuter>,
+        legacy_mutation_count: u32,
+    ) -> Result<()> {
+        msg!("migrate transmuter");
+        instructions::migrate_transmuter::handler(ctx, legacy_mutation_count)
+    }
+
+    pub fn migrate_mutation(ctx: Context<MigrateMutation>) -> Result<()> {
+        msg!("migrate mutation");
+        instructions::migrate_mutation::handler(ctx)
+    }
+
+    pub fn migrate_execution_receipt(ctx: Context<MigrateExecutionReceipt>) -> Result<()> {
+        msg!("migrate execution receipt");
+        instructions::migrate_execution_receipt::handler(ctx)
+    }
 }
@@ -1,6 +1,6 @@
 use crate::*;
 
-#[proc_macros::assert_size(240)]
+#[proc_macros::assert_size(352)]
 #[repr(C)]
 #[account]
 pub struct ExecutionReceipt {
@@ -18,8 +18,38 @@ pub struct ExecutionReceipt {
     pub vault_a: Option<Pubkey>, //option adds 0 to size
     pub vault_b: Option<Pubkey>,
     pub vault_c: Option<Pubkey>,
+    /// vault i belongs to transmuter.extra_banks[i]
+    pub extra_vaults: [Option<Pubkey>; 3],
 
-    _reserved: [u8; 32],
+    /// how many times this receipt has been reversed so far
+    pub reversal_count: u64,
+
+    /// loot mutations only - slot at which the execution was started,
+    /// the roll is revealed from the hash of the slot after it
+    pub commit_slot: u64,
+    /// loot mutations only - maker slot handed out, set on completion
+    pub loot_outcome: u8,
+
+    _reserved: [u8; 3],
+
+    /// lets a taker run the same mutation again with a fresh receipt (and fresh vaults)
+    /// 0 for the first run, which keeps the original seeds - see nonce_seed
+    pub nonce: u32,
+
+    /// payment of a pending execution, held until it completes (paid out to the maker) or is cancelled (refunded).
+    /// Lamports sit on this receipt, price tokens in a token account pda with seeds [b"payment_escrow", receipt]
+    pub escrowed_lamports: u64,
+    pub escrowed_amount: u64,
+}
+
+/// extra seed for the receipt & vault creator pdas of a taker's nth run of a mutation
+/// empty for nonce 0, so receipts & vaults created before nonces existed keep their addresses
+pub fn nonce_seed(nonce: u32) -> Vec<u8> {
+    if nonce == 0 {
+        vec![]
+    } else {
+        nonce.to_le_bytes().to_vec()
+    }
 }
 
 #[proc_macros::assert_size(4)]
@@ -31,6 +61,30 @@ pub enum ExecutionState {
     Complete,
 }
 
+impl From<LegacyExecutionReceipt> for ExecutionReceipt {
+    /// nothing was escrowed back then - pending executions were paid for upfront
+    fn from(legacy: LegacyExecutionReceipt) -> Self {
+        ExecutionReceipt {
+            transmuter: legacy.transmuter,
+            mutation: legacy.mutation,
+            taker: legacy.taker,
+            mutation_complete_ts: legacy.mutation_complete_ts,
+            state: legacy.state,
+            vault_a: legacy.vault_a,
+            vault_b: legacy.vault_b,
+            vault_c: legacy.vault_c,
+            extra_vaults: [None; 3],
+            reversal_count: 0,
+            commit_slot: 0,
+            loot_outcome: 0,
+            _reserved: [0; 3],
+            nonce: 0,
+            escrowed_lamports: 0,
+            escrowed_amount: 0,
+        }
+    }
+}
+
 impl ExecutionReceipt {
     pub fn is_not_started(&self) -> bool {
         self.state == ExecutionState::NotStarted
@@ -44,6 +98,16 @@ impl ExecutionReceipt {
         self.state == ExecutionState::Complete
     }
 
+    /// vault used for the given taker slot (a = 0, b, c, then the extra ones)
+    pub fn taker_vault(&self, index: u8) -> Option<Pubkey> {
+        match index {
+            0 => self.vault_a,
+            1 => self.vault_b,
+            2 => self.vault_c,
+            i => self.extra_vaults.get(i as usize - 3).copied().flatten(),
+        }
+    }
+
     pub fn record_mutation_complete_ts(&mut self, mutation_time_sec: u64) -> Result<()> {
         let now_ts = now_ts()?;
         self.mutation_complete_ts = now_ts.try_add(mutation_time_sec)?;
@@ -51,6 +115,30 @@ impl ExecutionReceipt {
         Ok(())
     }
 
+    pub fn record_reversal(&mut self) -> Result<()> {
+        self.reversal_count.try_add_assign(1)
+    }
+
+    /// back to square one, as if the pending execution never happened
+    pub fn record_cancellation(&mut self) {
+        self.state = ExecutionState::NotStarted;
+        self.loot_outcome = 0;
+        self.release_payment();
+    }
+
+    pub fn record_escrowed_payment(&mut self, lamports: u64, amount: u64) {
+        self.escrowed_lamports = lamports;
+        self.escrowed_amount = amount;
+    }
+
+    /// (lamports, price tokens) held for the pending execution, cleared once paid out / refunded
+    pub fn release_payment(&mut self) -> (u64, u64) {
+        let payment = (self.escrowed_lamports, self.escrowed_amount);
+        self.escrowed_lamports = 0;
+        self.escrowed_amount = 0;
+        payment
+    }
+
     pub fn mark_not_started(&mut self) {
         self.state = ExecutionState::NotStarted;
     }
@@ -59,6 +147,11 @@ impl ExecutionReceipt {
         self.state = ExecutionState::Pending;
     }
 
+    pub fn record_commit_slot(&mut self) -> Result<()> {
+        self.commit_slot = Clock::get()?.slot;
+        Ok(())
+    }
+
     pub fn try_mark_complete(&mut self) -> Result<()> {
         let now_ts = now_ts()?;
         require!(now_ts >= self.mutation_complete_ts, MutationNotComplete);
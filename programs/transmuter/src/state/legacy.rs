@@ -0,0 +1,227 @@
+//! Account layouts from before the program's first upgrade, only kept around to read accounts
+//! that haven't been migrated yet (see the migrate_* instructions). Never written.
+
+use crate::*;
+
+/// reads an account still in its legacy layout, making sure it hasn't been migrated already
+pub fn read_legacy<T: AnchorDeserialize>(info: &AccountInfo, discriminator: [u8; 8]) -> Result<T> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() == 8 + std::mem::size_of::<T>(),
+        AccountAlreadyMigrated
+    );
+    require!(data[..8] == discriminator, AccountDiscriminatorMismatch);
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AnchorSerializationIssue))
+}
+
+/// grows a legacy account to fit its latest layout (topping up rent from the payer), then writes it
+pub fn write_migrated<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    migrated: &T,
+) -> Result<()> {
+    let new_len = 8 + std::mem::size_of::<T>();
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer_lamports(payer, account, system_program, top_up)?;
+    }
+    account.realloc(new_len, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    let dst: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut std::io::Cursor::new(dst))
+}
+
+#[proc_macros::assert_size(260)]
+#[repr(C)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyTransmuter {
+    pub version: u16,
+
+    pub owner: Pubkey,
+
+    pub authority: Pubkey,
+    pub authority_seed: Pubkey,
+    pub authority_bump_seed: [u8; 1],
+
+    pub bank_a: Pubkey,
+    pub bank_b: Pubkey,
+    pub bank_c: Pubkey,
+
+    _reserved: [u8; 64],
+}
+
+#[proc_macros::assert_size(624)]
+#[repr(C)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMutation {
+    pub transmuter: Pubkey,
+
+    pub config: LegacyMutationConfig,
+
+    pub token_a_escrow: Pubkey,
+    pub token_b_escrow: Option<Pubkey>,
+    pub token_c_escrow: Option<Pubkey>,
+
+    pub total_uses: u64,
+
+    pub remaining_uses: u64,
+
+    pub state: MutationState,
+
+    pub name: [u8; 32],
+
+    _reserved: [u8; 64],
+}
+
+#[proc_macros::assert_size(368)]
+#[repr(C)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMutationConfig {
+    pub taker_token_a: TakerTokenConfig,
+    pub taker_token_b: Option<TakerTokenConfig>,
+    pub taker_token_c: Option<TakerTokenConfig>,
+    pub maker_token_a: LegacyMakerTokenConfig,
+    pub maker_token_b: Option<LegacyMakerTokenConfig>,
+    pub maker_token_c: Option<LegacyMakerTokenConfig>,
+    pub price: LegacyPriceConfig,
+
+    pub mutation_duration_sec: u64,
+
+    pub reversible: bool,
+
+    _reserved: [u8; 32],
+}
+
+/// always funded from an escrow
+#[proc_macros::assert_size(48)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMakerTokenConfig {
+    pub mint: Pubkey,
+
+    pub total_funding: u64,
+
+    pub amount_per_use: u64,
+}
+
+/// lamports only
+#[proc_macros::assert_size(16)]
+#[repr(C)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyPriceConfig {
+    pub price_lamports: u64,
+
+    pub reversal_price_lamports: i64,
+}
+
+#[proc_macros::assert_size(240)]
+#[repr(C)]
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyExecutionReceipt {
+    pub transmuter: Pubkey,
+
+    pub mutation: Pubkey,
+
+    pub taker: Pubkey,
+
+    pub mutation_complete_ts: u64,
+
+    pub state: ExecutionState,
+
+    pub vault_a: Option<Pubkey>,
+    pub vault_b: Option<Pubkey>,
+    pub vault_c: Option<Pubkey>,
+
+    _reserved: [u8; 32],
+}
+
+impl From<LegacyMakerTokenConfig> for MakerTokenConfig {
+    fn from(legacy: LegacyMakerTokenConfig) -> Self {
+        MakerTokenConfig {
+            mint: legacy.mint,
+            total_funding: legacy.total_funding,
+            amount_per_use: legacy.amount_per_use,
+            source: MakerTokenSource::Escrow,
+        }
+    }
+}
+
+impl From<LegacyPriceConfig> for PriceConfig {
+    fn from(legacy: LegacyPriceConfig) -> Self {
+        PriceConfig {
+            price_lamports: legacy.price_lamports,
+            reversal_price_lamports: legacy.reversal_price_lamports,
+            price_mint: None,
+            price_amount: 0,
+            reversal_price_amount: 0,
+            pricing_mode: PricingMode::Fixed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutation_carries_over() {
+        let maker_token = LegacyMakerTokenConfig {
+            mint: Pubkey::new_unique(),
+            total_funding: 30,
+            amount_per_use: 3,
+        };
+        let legacy = LegacyMutation {
+            transmuter: Pubkey::new_unique(),
+            config: LegacyMutationConfig {
+                taker_token_a: TakerTokenConfig {
+                    gem_bank: Pubkey::new_unique(),
+                    required_amount: 2,
+                    required_units: RequiredUnits::Gems,
+                    vault_action: VaultAction::Lock,
+                },
+                taker_token_b: None,
+                taker_token_c: None,
+                maker_token_a: maker_token,
+                maker_token_b: Some(maker_token),
+                maker_token_c: None,
+                price: LegacyPriceConfig {
+                    price_lamports: 100,
+                    reversal_price_lamports: -50,
+                },
+                mutation_duration_sec: 10,
+                reversible: true,
+                _reserved: [0; 32],
+            },
+            token_a_escrow: Pubkey::new_unique(),
+            token_b_escrow: Some(Pubkey::new_unique()),
+            token_c_escrow: None,
+            total_uses: 10,
+            remaining_uses: 7,
+            state: MutationState::Available,
+            name: [1; 32],
+            _reserved: [0; 64],
+        };
+
+        // read back the way it's stored on chain
+        let data = legacy.try_to_vec().unwrap();
+        let legacy = LegacyMutation::deserialize(&mut data.as_slice()).unwrap();
+        let transmuter = legacy.transmuter;
+        let mutation = Mutation::from(legacy);
+
+        assert_eq!(mutation.transmuter, transmuter);
+        assert_eq!(mutation.pending_executions, 0);
+        assert!(!mutation.has_revenue_splits());
+        mutation.config.assert_is_valid().unwrap();
+        mutation.config.assert_is_available(0).unwrap();
+        assert_eq!(mutation.config.maker_tokens().count(), 2);
+        assert!(!mutation.config.maker_token_a.is_minted());
+        assert!(mutation.config.price.price_mint.is_none());
+        assert_eq!(mutation.config.price.pricing_mode, PricingMode::Fixed);
+        assert_eq!(mutation.config.price.price_lamports, 100);
+        assert_eq!(mutation.config.price.reversal_price_lamports, -50);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use anchor_lang::solana_program::keccak;
+use std::convert::TryInto;
+
+/// one weight per maker slot - a, b, c, then the 2 extra ones
+pub const LOOT_TABLE_SIZE: usize = 5;
+
+/// rolls are revealed from the hash of the first slot after the commit, which only stays in the
+/// SlotHashes sysvar for ~512 slots, so mutations that reveal a roll have to be completable well within that
+pub const MAX_REVEAL_MUTATION_DURATION_SEC: u64 = 60;
+
+/// Weighted table of outcomes for loot-box mutations.
+/// Instead of handing out every maker token on each execution, a single maker slot is rolled:
+/// outcome i hands out maker slot i (a, b, c, then the extra ones).
+#[proc_macros::assert_size(10)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct LootTable {
+    /// relative odds of each outcome, all zeros = not a loot mutation
+    pub weights: [u16; LOOT_TABLE_SIZE],
+}
+
+impl LootTable {
+    pub fn is_enabled(&self) -> bool {
+        self.weights.iter().any(|w| *w > 0)
+    }
+
+    /// weights can only be set on maker slots that are in use
+    pub fn assert_is_valid(&self, config: &MutationConfig) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let slots_in_use = [
+            true,
+            config.maker_token_b.is_some(),
+            config.maker_token_c.is_some(),
+            config.extra_maker_tokens[0].is_some(),
+            config.extra_maker_tokens[1].is_some(),
+        ];
+        for (weight, in_use) in self.weights.iter().zip(slots_in_use.iter()) {
+            require!(*weight == 0 || *in_use, InvalidLootTable);
+        }
+        require!(
+            config.mutation_duration_sec <= MAX_REVEAL_MUTATION_DURATION_SEC,
+            InvalidLootTable
+        );
+
+        Ok(())
+    }
+
+    /// picks an outcome with odds proportional to its weight
+    pub fn roll(&self, seed: [u8; 32]) -> Result<u8> {
+        let total_weight = self.weights.iter().map(|w| *w as u64).sum::<u64>();
+        require!(total_weight > 0, InvalidLootTable);
+
+        let mut roll = u64::from_le_bytes(seed[..8].try_into().unwrap()) % total_weight;
+        for (i, weight) in self.weights.iter().enumerate() {
+            let weight = *weight as u64;
+            if roll < weight {
+                return Ok(i as u8);
+            }
+            roll -= weight;
+        }
+
+        Err(error!(ErrorCode::InvalidLootTable))
+    }
+
+    /// outcome of a completed execution - rolled from the revealed seed, or if the reveal expired,
+    /// the most likely outcome (lowest index on ties). The fallback is fixed when the execution starts,
+    /// so waiting out a bad roll never gets the taker a new one - makers should put the least
+    /// valuable prize on the heaviest weight
+    pub fn resolve(&self, seed: Option<[u8; 32]>) -> Result<u8> {
+        match seed {
+            Some(seed) => self.roll(seed),
+            None => {
+                let (outcome, _) = self
+                    .weights
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .max_by_key(|(_, weight)| **weight)
+                    .unwrap();
+                Ok(outcome as u8)
+            }
+        }
+    }
+}
+
+/// mixes in the receipt so that executions committed in the same slot roll differently
+pub fn reveal_seed(slot_hash: &[u8; 32], receipt: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[slot_hash.as_ref(), receipt.as_ref()]).0
+}
+
+/// Reads the hash of the first slot after `commit_slot` from the SlotHashes sysvar.
+/// Errors if no later slot has been recorded yet, returns None if the commit slot has
+/// already fallen out of the sysvar (so we can no longer tell which slot came right after it)
+/// - the roll then resolves to a fallback, never to a fresh commit the taker could retry.
+/// Parsed by hand - deserializing all 512 entries is too expensive.
+pub fn find_reveal_slot_hash(
+    slot_hashes: &AccountInfo,
+    commit_slot: u64,
+) -> Result<Option<[u8; 32]>> {
+    const ENTRY_LEN: usize = 8 + 32;
+
+    let data = slot_hashes.try_borrow_data()?;
+    let len = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+
+    // entries are sorted newest first
+    let mut reveal_hash = None;
+    for i in 0..len {
+        let start = 8 + i * ENTRY_LEN;
+        let slot = u64::from_le_bytes(data[start..start + 8].try_into().unwrap());
+        if slot <= commit_slot {
+            require!(reveal_hash.is_some(), LootNotYetRevealable);
+            return Ok(reveal_hash);
+        }
+        reveal_hash = Some(data[start + 8..start + ENTRY_LEN].try_into().unwrap());
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed(roll: u64) -> [u8; 32] {
+        let mut seed = [0; 32];
+        seed[..8].copy_from_slice(&roll.to_le_bytes());
+        seed
+    }
+
+    /// SlotHashes sysvar holding the 512 slots up to `newest_slot`, newest first
+    fn slot_hashes_data(newest_slot: u64, hash_byte: u8) -> Vec<u8> {
+        let mut data = 512u64.to_le_bytes().to_vec();
+        for slot in (newest_slot - 511..=newest_slot).rev() {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[hash_byte; 32]);
+        }
+        data
+    }
+
+    fn resolve_at(table: &LootTable, commit_slot: u64, newest_slot: u64, hash_byte: u8) -> u8 {
+        let key = Pubkey::default();
+        let mut lamports = 0;
+        let mut data = slot_hashes_data(newest_slot, hash_byte);
+        let slot_hashes =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &key, false, 0);
+        let seed = find_reveal_slot_hash(&slot_hashes, commit_slot)
+            .unwrap()
+            .map(|slot_hash| reveal_seed(&slot_hash, &key));
+        table.resolve(seed).unwrap()
+    }
+
+    #[test]
+    fn test_roll() {
+        let table = LootTable {
+            weights: [70, 0, 25, 5, 0],
+        };
+        assert_eq!(table.roll(seed(0)).unwrap(), 0);
+        assert_eq!(table.roll(seed(69)).unwrap(), 0);
+        assert_eq!(table.roll(seed(70)).unwrap(), 2);
+        assert_eq!(table.roll(seed(94)).unwrap(), 2);
+        assert_eq!(table.roll(seed(95)).unwrap(), 3);
+        assert_eq!(table.roll(seed(99)).unwrap(), 3);
+        // wraps around
+        assert_eq!(table.roll(seed(100)).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_expired_reveal_is_fixed() {
+        let table = LootTable {
+            weights: [10, 0, 70, 70, 20],
+        };
+
+        // revealed rolls depend on the slot hash
+        assert_eq!(resolve_at(&table, 1000, 1100, 0), 3);
+        assert_eq!(resolve_at(&table, 1000, 1100, 3), 4);
+
+        // once expired, whatever the sysvar holds, it's always the heaviest outcome
+        for &(newest_slot, hash_byte) in [(1600, 0), (1600, 3), (5000, 0), (5000, 3)].iter() {
+            assert_eq!(resolve_at(&table, 1000, newest_slot, hash_byte), 2);
+        }
+    }
+}
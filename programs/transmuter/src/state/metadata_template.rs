@@ -0,0 +1,110 @@
+use crate::*;
+use mpl_token_metadata::state::{DataV2, Metadata, MAX_NAME_LENGTH, MAX_URI_LENGTH};
+
+pub const METADATA_TEMPLATE_SPACE: usize =
+    8 + 32 + (1 + 4 + MAX_NAME_LENGTH) + (4 + MAX_URI_LENGTH);
+
+/// longest a pubkey gets in base58
+const MAX_MINT_STR_LENGTH: usize = 44;
+
+/// New metadata for NFTs mutated with VaultAction::UpdateMetadata.
+/// Both strings can contain {mint} and {name} placeholders, filled with the NFT's mint and current name.
+/// Not size-asserted since it holds strings, space is reserved for the max lengths metaplex allows.
+#[account]
+pub struct MetadataTemplate {
+    pub mutation: Pubkey,
+
+    /// if not set, the NFT keeps its name
+    pub name: Option<String>,
+
+    pub uri: String,
+}
+
+impl MetadataTemplate {
+    /// the uri is checked against its longest possible rendering, since a cut off uri would be broken -
+    /// rendered names are truncated instead
+    pub fn assert_is_valid(&self) -> Result<()> {
+        if let Some(name) = &self.name {
+            require!(name.len() <= MAX_NAME_LENGTH, InvalidMetadataTemplate);
+        }
+        require!(
+            !self.uri.is_empty() && Self::max_rendered_len(&self.uri) <= MAX_URI_LENGTH,
+            InvalidMetadataTemplate
+        );
+        Ok(())
+    }
+
+    /// length once filled with the longest mint & name there can be
+    fn max_rendered_len(template: &str) -> usize {
+        template.len()
+            + template.matches("{mint}").count() * (MAX_MINT_STR_LENGTH - "{mint}".len())
+            + template.matches("{name}").count() * (MAX_NAME_LENGTH - "{name}".len())
+    }
+
+    /// everything other than name & uri is carried over as is
+    pub fn render(&self, metadata: &Metadata) -> DataV2 {
+        // metaplex pads stored strings with null bytes
+        let current_name = metadata.data.name.trim_matches(char::from(0));
+        let mint = metadata.mint.to_string();
+        let fill = |template: &str| {
+            template
+                .replace("{mint}", &mint)
+                .replace("{name}", current_name)
+        };
+
+        DataV2 {
+            name: match &self.name {
+                Some(name) => truncate(fill(name), MAX_NAME_LENGTH),
+                None => current_name.to_string(),
+            },
+            symbol: metadata.data.symbol.trim_matches(char::from(0)).to_string(),
+            uri: fill(&self.uri),
+            seller_fee_basis_points: metadata.data.seller_fee_basis_points,
+            creators: metadata.data.creators.clone(),
+            collection: metadata.collection.clone(),
+            uses: metadata.uses.clone(),
+        }
+    }
+}
+
+/// cuts a string down to at most max_len bytes, without splitting a char
+fn truncate(mut s: String, max_len: usize) -> String {
+    while s.len() > max_len {
+        s.pop();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(name: Option<&str>, uri: &str) -> MetadataTemplate {
+        MetadataTemplate {
+            mutation: Pubkey::default(),
+            name: name.map(|name| name.to_string()),
+            uri: uri.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_uri_worst_case_length() {
+        let uri = format!("https://a.com/{}", "{mint}".repeat(4));
+        assert!(template(None, &uri).assert_is_valid().is_ok());
+        // fine as typed, but too long once filled with 44 char mints
+        let uri = format!("https://a.com/{}", "{mint}".repeat(5));
+        assert!(template(None, &uri).assert_is_valid().is_err());
+        let uri = format!("https://a.com/{}", "{name}".repeat(5));
+        assert!(template(None, &uri).assert_is_valid().is_ok());
+        let uri = format!("https://a.com/{}", "{name}".repeat(6));
+        assert!(template(None, &uri).assert_is_valid().is_err());
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("gem".to_string(), 32), "gem");
+        assert_eq!(truncate("gem (mutated)".to_string(), 3), "gem");
+        // doesn't split the 2 byte é
+        assert_eq!(truncate("gemé".to_string(), 4), "gem");
+    }
+}
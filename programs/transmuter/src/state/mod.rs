@@ -1,7 +1,23 @@
 pub mod execution_receipt;
+pub mod legacy;
+pub mod loot_table;
+pub mod metadata_template;
 pub mod mutation;
+pub mod nft_template_list;
+pub mod protocol_config;
+pub mod taker_list;
+pub mod taker_record;
 pub mod transmuter;
+pub mod transmuter_role;
 
 pub use execution_receipt::*;
+pub use legacy::*;
+pub use loot_table::*;
+pub use metadata_template::*;
 pub use mutation::*;
+pub use nft_template_list::*;
+pub use protocol_config::*;
+pub use taker_list::*;
+pub use taker_record::*;
 pub use transmuter::*;
+pub use transmuter_role::*;
@@ -1,8 +1,10 @@
 use crate::ErrorCode::NoMoreUsesLeft;
 use crate::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::Mint;
 use gem_bank::state::Vault;
 
-#[proc_macros::assert_size(624)]
+#[proc_macros::assert_size(1280)]
 #[repr(C)]
 #[account]
 pub struct Mutation {
@@ -15,6 +17,8 @@ pub struct Mutation {
     pub token_a_escrow: Pubkey,
     pub token_b_escrow: Option<Pubkey>, //option adds 4 to size
     pub token_c_escrow: Option<Pubkey>, //option adds 4 to size
+    /// escrows for config.extra_maker_tokens, same order
+    pub extra_escrows: [Option<Pubkey>; 2],
 
     total_uses: u64,
 
@@ -24,7 +28,24 @@ pub struct Mutation {
 
     pub name: [u8; 32],
 
-    _reserved: [u8; 64],
+    /// if empty, all payments go to the transmuter owner
+    /// filled from the front, unused entries have share_bps = 0
+    pub revenue_splits: [RevenueSplit; 4],
+
+    /// executions that have been started but not yet completed (or cancelled)
+    /// while > 0 the mutation can't be destroyed, or the takers' rewards would be gone
+    pub pending_executions: u64,
+
+    /// optional allow/denylist of takers, can be rotated by the owner at any point
+    pub taker_list: TakerList,
+
+    /// templates for nft rewards, set once via init_nft_template_list
+    pub nft_template_list: Option<Pubkey>,
+
+    /// if set, no new executions can be started (pending ones can still complete / be reversed)
+    pub paused: bool,
+
+    _reserved: [u8; 19],
 }
 
 impl Mutation {
@@ -42,12 +63,103 @@ impl Mutation {
         Ok(())
     }
 
+    pub fn assert_not_paused(&self, transmuter: &Transmuter) -> Result<()> {
+        require!(!transmuter.paused && !self.paused, MutationPaused);
+        Ok(())
+    }
+
+    /// whether new executions can still be started (ignores start_ts - those just haven't started yet)
+    pub fn accepts_executions(&self, transmuter: &Transmuter, now_ts: u64) -> bool {
+        let ended = matches!(self.config.end_ts, Some(end_ts) if now_ts >= end_ts);
+        !transmuter.paused && !self.paused && !ended
+    }
+
+    /// tops up both total & remaining uses, eg when a mutation sells out
+    pub fn add_uses(&mut self, uses: u64) -> Result<()> {
+        require!(self.total_uses != UNCAPPED_USES, IncorrectFunding);
+        self.total_uses.try_add_assign(uses)?;
+        self.remaining_uses.try_add_assign(uses)?;
+        self.update_state();
+        Ok(())
+    }
+
     pub fn increment_uses(&mut self) -> Result<()> {
         self.remaining_uses.try_add_assign(1)?;
         self.update_state();
         Ok(())
     }
 
+    pub fn start_pending_execution(&mut self) -> Result<()> {
+        self.pending_executions.try_add_assign(1)
+    }
+
+    pub fn finish_pending_execution(&mut self) -> Result<()> {
+        self.pending_executions.try_sub_assign(1)
+    }
+
+    /// only price (incl. reversal price), duration & name can change once created - slots,
+    /// reversibility & rolls are fixed for good, as pending / completed executions rely on them.
+    /// Price mint can only change while no executions are pending, as their payment is escrowed in it
+    pub fn try_update(&mut self, price: PriceConfig, mutation_duration_sec: u64) -> Result<()> {
+        if self.config.price.price_mint != price.price_mint {
+            require!(self.pending_executions == 0, ImmutableMutationConfig);
+        }
+
+        let mut config = self.config;
+        config.price = price;
+        config.mutation_duration_sec = mutation_duration_sec;
+        config.assert_is_valid()?;
+        self.config = config;
+
+        Ok(())
+    }
+
+    pub fn set_revenue_splits(&mut self, revenue_splits: &[RevenueSplit]) -> Result<()> {
+        RevenueSplit::assert_are_valid(revenue_splits)?;
+        for (i, split) in revenue_splits.iter().enumerate() {
+            self.revenue_splits[i] = *split;
+        }
+        Ok(())
+    }
+
+    /// (lamports, tokens) a taker has to pay to start an execution right now
+    pub fn current_price(&self, now_ts: u64) -> Result<(u64, u64)> {
+        let price = &self.config.price;
+        let uses_consumed = self.total_uses.try_sub(self.remaining_uses)?;
+        let multiplier_bps = price
+            .pricing_mode
+            .price_multiplier_bps(now_ts, uses_consumed)?;
+
+        Ok((
+            apply_bps(price.price_lamports, multiplier_bps)?,
+            apply_bps(price.price_amount, multiplier_bps)?,
+        ))
+    }
+
+    /// escrow of the given maker slot (a = 0, b, c, then the extra ones), None if minted
+    pub fn maker_escrow(&self, index: u8) -> Option<Pubkey> {
+        match index {
+            0 => Some(self.token_a_escrow),
+            1 => self.token_b_escrow,
+            2 => self.token_c_escrow,
+            i => self.extra_escrows.get(i as usize - 3).copied().flatten(),
+        }
+    }
+
+    pub fn has_revenue_splits(&self) -> bool {
+        self.revenue_splits[0].share_bps > 0
+    }
+
+    /// only returns the splits that are in use
+    pub fn active_revenue_splits(&self) -> &[RevenueSplit] {
+        let count = self
+            .revenue_splits
+            .iter()
+            .take_while(|split| split.share_bps > 0)
+            .count();
+        &self.revenue_splits[..count]
+    }
+
     fn update_state(&mut self) {
         if self.remaining_uses == 0 {
             self.state = MutationState::Exhausted;
@@ -57,6 +169,33 @@ impl Mutation {
     }
 }
 
+impl From<LegacyMutation> for Mutation {
+    /// pending executions are counted as their receipts are migrated
+    fn from(legacy: LegacyMutation) -> Self {
+        Mutation {
+            transmuter: legacy.transmuter,
+            config: legacy.config.into(),
+            token_a_escrow: legacy.token_a_escrow,
+            token_b_escrow: legacy.token_b_escrow,
+            token_c_escrow: legacy.token_c_escrow,
+            extra_escrows: [None; 2],
+            total_uses: legacy.total_uses,
+            remaining_uses: legacy.remaining_uses,
+            state: legacy.state,
+            name: legacy.name,
+            revenue_splits: [RevenueSplit::default(); MAX_REVENUE_SPLITS],
+            pending_executions: 0,
+            taker_list: TakerList {
+                list_type: TakerListType::None,
+                root: [0; 32],
+            },
+            nft_template_list: None,
+            paused: false,
+            _reserved: [0; 19],
+        }
+    }
+}
+
 #[proc_macros::assert_size(4)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -65,7 +204,7 @@ pub enum MutationState {
     Exhausted,
 }
 
-#[proc_macros::assert_size(368)]
+#[proc_macros::assert_size(784)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct MutationConfig {
@@ -73,45 +212,189 @@ pub struct MutationConfig {
     pub taker_token_b: Option<TakerTokenConfig>, //option adds 0 to size
     pub taker_token_c: Option<TakerTokenConfig>,
     pub maker_token_a: MakerTokenConfig,
-    pub maker_token_b: Option<MakerTokenConfig>, //option adds 8 to size ¯\_(ツ)_/¯
+    pub maker_token_b: Option<MakerTokenConfig>, //option adds 0 to size
     pub maker_token_c: Option<MakerTokenConfig>,
+
+    /// slots beyond a/b/c, needed for larger recipes (up to 6 inputs and 5 outputs)
+    /// extra taker token i is deposited into transmuter.extra_banks[i]
+    /// accounts for these slots are passed as remaining accounts
+    pub extra_taker_tokens: [Option<TakerTokenConfig>; 3],
+    pub extra_maker_tokens: [Option<MakerTokenConfig>; 2],
+
     pub price: PriceConfig,
 
     pub mutation_duration_sec: u64,
 
     pub reversible: bool,
 
-    _reserved: [u8; 32],
+    /// only relevant if reversible
+    pub reversal: ReversalConfig,
+
+    /// new executions can only be started within [start_ts, end_ts)
+    /// pending executions can be completed at any point
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+
+    /// max executions a single taker can have started (and not reversed), 0 = unlimited
+    pub max_executions_per_taker: u64,
+
+    /// min time between the starts of a single taker's executions, 0 = no cooldown
+    pub taker_cooldown_sec: u64,
+
+    /// if set, each execution hands out a single randomly rolled maker slot instead of all of them
+    pub loot_table: LootTable,
+
+    /// if set, each execution also mints a fresh NFT from the mutation's nft template list
+    pub nft_reward: NftRewardMode,
+
+    /// out of 10_000, kept from the price refunded when a taker cancels a pending execution
+    pub cancellation_penalty_bps: u16,
+
+    _reserved: [u8; 6],
+}
+
+impl From<LegacyMutationConfig> for MutationConfig {
+    /// none of the features added since are switched on, so the mutation keeps behaving as before
+    fn from(legacy: LegacyMutationConfig) -> Self {
+        MutationConfig {
+            taker_token_a: legacy.taker_token_a,
+            taker_token_b: legacy.taker_token_b,
+            taker_token_c: legacy.taker_token_c,
+            maker_token_a: legacy.maker_token_a.into(),
+            maker_token_b: legacy.maker_token_b.map(Into::into),
+            maker_token_c: legacy.maker_token_c.map(Into::into),
+            extra_taker_tokens: [None; 3],
+            extra_maker_tokens: [None; 2],
+            price: legacy.price.into(),
+            mutation_duration_sec: legacy.mutation_duration_sec,
+            reversible: legacy.reversible,
+            reversal: ReversalConfig {
+                window_sec: 0,
+                end_price_bps: BPS_DENOMINATOR,
+                max_reversals: 0,
+            },
+            start_ts: None,
+            end_ts: None,
+            max_executions_per_taker: 0,
+            taker_cooldown_sec: 0,
+            loot_table: LootTable::default(),
+            nft_reward: NftRewardMode::None,
+            cancellation_penalty_bps: 0,
+            _reserved: [0; 6],
+        }
+    }
 }
 
 impl MutationConfig {
     /// for a mutation to be reversible, all vaults must be set to Lock
+    /// token prices are only allowed if a price mint is set
+    /// availability window has to end after it starts
+    /// loot table can only reference maker slots in use
+    /// rolled loot & nft rewards can't be reversed, and random nfts have to be revealed in time
+    /// cancellation penalty can't be more than the whole price
     pub fn assert_is_valid(&self) -> Result<()> {
         if self.reversible {
-            require!(
-                self.taker_token_a.vault_action == VaultAction::Lock,
-                VaultsNotSetToLock
-            );
-            if let Some(taker_token_b) = self.taker_token_b {
+            for taker_token in self.taker_tokens() {
                 require!(
-                    taker_token_b.vault_action == VaultAction::Lock,
-                    VaultsNotSetToLock
-                );
-            }
-            if let Some(taker_token_c) = self.taker_token_c {
-                require!(
-                    taker_token_c.vault_action == VaultAction::Lock,
+                    taker_token.vault_action == VaultAction::Lock,
                     VaultsNotSetToLock
                 );
             }
         }
+        if let (Some(start_ts), Some(end_ts)) = (self.start_ts, self.end_ts) {
+            require!(start_ts < end_ts, InvalidAvailabilityWindow);
+        }
+        self.price.assert_is_valid()?;
+        self.loot_table.assert_is_valid(self)?;
+        if self.loot_table.is_enabled() {
+            require!(!self.reversible, InvalidLootTable);
+        }
+        if self.nft_reward != NftRewardMode::None {
+            require!(!self.reversible, InvalidNftReward);
+        }
+        require!(
+            self.cancellation_penalty_bps as u64 <= BPS_DENOMINATOR,
+            InvalidCancellationPenalty
+        );
+        if self.nft_reward == NftRewardMode::Random {
+            require!(
+                self.mutation_duration_sec <= MAX_REVEAL_MUTATION_DURATION_SEC,
+                InvalidNftReward
+            );
+        }
+        Ok(())
+    }
+
+    /// all taker tokens in use - a, b, c, then the extra ones
+    pub fn taker_tokens(&self) -> impl Iterator<Item = &TakerTokenConfig> {
+        std::iter::once(&self.taker_token_a)
+            .chain(self.taker_token_b.iter())
+            .chain(self.taker_token_c.iter())
+            .chain(self.extra_taker_tokens.iter().flatten())
+    }
+
+    /// all maker tokens in use - a, b, c, then the extra ones
+    pub fn maker_tokens(&self) -> impl Iterator<Item = &MakerTokenConfig> {
+        std::iter::once(&self.maker_token_a)
+            .chain(self.maker_token_b.iter())
+            .chain(self.maker_token_c.iter())
+            .chain(self.extra_maker_tokens.iter().flatten())
+    }
+
+    /// taker tokens in use, with their slot index (a = 0, b, c, then the extra ones)
+    pub fn taker_slots(&self) -> impl Iterator<Item = (u8, TakerTokenConfig)> + '_ {
+        std::iter::once(Some(self.taker_token_a))
+            .chain(std::iter::once(self.taker_token_b))
+            .chain(std::iter::once(self.taker_token_c))
+            .chain(self.extra_taker_tokens.iter().copied())
+            .enumerate()
+            .filter_map(|(i, taker_token)| taker_token.map(|t| (i as u8, t)))
+    }
+
+    /// maker tokens in use, with their slot index (a = 0, b, c, then the extra ones)
+    pub fn maker_slots(&self) -> impl Iterator<Item = (u8, MakerTokenConfig)> + '_ {
+        std::iter::once(Some(self.maker_token_a))
+            .chain(std::iter::once(self.maker_token_b))
+            .chain(std::iter::once(self.maker_token_c))
+            .chain(self.extra_maker_tokens.iter().copied())
+            .enumerate()
+            .filter_map(|(i, maker_token)| maker_token.map(|t| (i as u8, t)))
+    }
+
+    /// loot tables & random nft rewards are rolled from a slot hash on completion
+    pub fn needs_reveal(&self) -> bool {
+        self.loot_table.is_enabled() || self.nft_reward == NftRewardMode::Random
+    }
+
+    /// mutations with a roll always go through pending, to commit to it before it's revealed
+    pub fn needs_pending_phase(&self) -> bool {
+        self.mutation_duration_sec > 0 || self.needs_reveal()
+    }
+
+    /// rolled executions can't be cancelled, or takers could back out of a bad roll once it's revealable
+    pub fn can_cancel(&self) -> bool {
+        !self.needs_reveal()
+    }
+
+    /// whether the given maker slot (a, b, c, then the extra ones) is handed out for this roll
+    pub fn hands_out_maker_slot(&self, slot: u8, loot_outcome: u8) -> bool {
+        !self.loot_table.is_enabled() || slot == loot_outcome
+    }
+
+    pub fn assert_is_available(&self, now_ts: u64) -> Result<()> {
+        if let Some(start_ts) = self.start_ts {
+            require!(now_ts >= start_ts, MutationNotYetAvailable);
+        }
+        if let Some(end_ts) = self.end_ts {
+            require!(now_ts < end_ts, MutationNoLongerAvailable);
+        }
         Ok(())
     }
 }
 
 #[proc_macros::assert_size(4)]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum RequiredUnits {
     RarityPoints,
     Gems,
@@ -120,7 +403,7 @@ pub enum RequiredUnits {
 /// Token required FROM taker
 #[proc_macros::assert_size(48)]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub struct TakerTokenConfig {
     /// each gem bank has a whitelist with mints/creators allowed / not allowed
     pub gem_bank: Pubkey,
@@ -139,38 +422,61 @@ impl TakerTokenConfig {
         Ok(())
     }
 
-    /// verifies taker has indeed fulfilled the requirements set out by maker
+    /// verifies taker has indeed fulfilled the requirements set out by maker.
+    /// Burned / updated vaults are consumed whole, so they have to hold exactly the required amount
     pub fn assert_sufficient_amount(&self, vault: &Account<Vault>) -> Result<()> {
-        match self.required_units {
+        let taker_amount = match self.required_units {
             RequiredUnits::RarityPoints => {
                 let taker_rarity_points = vault.rarity_points;
                 require!(
                     taker_rarity_points >= self.required_amount,
                     InsufficientVaultRarityPoints
                 );
+                taker_rarity_points
             }
             RequiredUnits::Gems => {
                 let taker_gems = vault.gem_count;
                 require!(taker_gems >= self.required_amount, InsufficientVaultGems);
+                taker_gems
             }
+        };
+
+        if self.vault_action.consumes_gems() {
+            require!(taker_amount == self.required_amount, ExcessVaultGems);
         }
 
         Ok(())
     }
 }
 
+/// pass as uses to create a mutation that never runs out (all maker tokens have to be minted)
+pub const UNCAPPED_USES: u64 = u64::MAX;
+
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum MakerTokenSource {
+    /// pre-funded by the maker into an escrow on init_mutation
+    Escrow,
+    /// minted on each execution (and burned on reversal), transmuter authority has to be the mint authority
+    Mint,
+}
+
 /// Token returned TO taker
-#[proc_macros::assert_size(48)]
+#[proc_macros::assert_size(56)]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub struct MakerTokenConfig {
     pub mint: Pubkey,
 
+    /// has to be 0 for minted tokens
     pub total_funding: u64,
 
     /// in theory could be backcalculated, but making the user specify this upfront
     /// makes it more robust and prevents errors
     pub amount_per_use: u64,
+
+    pub source: MakerTokenSource,
 }
 
 impl MakerTokenConfig {
@@ -180,14 +486,46 @@ impl MakerTokenConfig {
         Ok(())
     }
 
+    pub fn is_minted(&self) -> bool {
+        self.source == MakerTokenSource::Mint
+    }
+
     /// verifies the math of uses * funding per use = total funding
+    /// minted tokens aren't funded upfront, so can also have uncapped uses
     pub fn assert_sufficient_funding(&self, uses: u64) -> Result<()> {
+        if self.is_minted() {
+            require!(self.total_funding == 0, IncorrectFunding);
+            return Ok(());
+        }
+        require!(uses != UNCAPPED_USES, IncorrectFunding);
         require!(
             self.total_funding == uses.try_mul(self.amount_per_use)?,
             IncorrectFunding
         );
         Ok(())
     }
+
+    /// records funding for additional uses, returns the amount that has to go into escrow
+    /// (always 0 for minted tokens)
+    pub fn add_funding(&mut self, uses: u64) -> Result<u64> {
+        if self.is_minted() {
+            return Ok(0);
+        }
+        let funding = uses.try_mul(self.amount_per_use)?;
+        self.total_funding.try_add_assign(funding)?;
+        Ok(funding)
+    }
+
+    /// minted tokens need the transmuter authority to be able to mint them
+    pub fn assert_can_mint(&self, mint: &Mint, authority: Pubkey) -> Result<()> {
+        if self.is_minted() {
+            require!(
+                mint.mint_authority == COption::Some(authority),
+                MintAuthorityMissing
+            );
+        }
+        Ok(())
+    }
 }
 
 #[proc_macros::assert_size(4)]
@@ -197,9 +535,21 @@ pub enum VaultAction {
     ChangeOwner,
     Lock,
     DoNothing,
+    /// gems are withdrawn from the vault and burned, rent goes back to the taker
+    Burn,
+    /// NFTs in the vault get their metadata rewritten from the mutation's metadata template,
+    /// then are left with the taker (transmuter authority has to be their update authority)
+    UpdateMetadata,
+}
+
+impl VaultAction {
+    /// every gem in the vault is processed, not just the required amount
+    pub fn consumes_gems(&self) -> bool {
+        matches!(self, VaultAction::Burn | VaultAction::UpdateMetadata)
+    }
 }
 
-#[proc_macros::assert_size(16)]
+#[proc_macros::assert_size(104)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct PriceConfig {
@@ -207,4 +557,351 @@ pub struct PriceConfig {
 
     /// negative means refund
     pub reversal_price_lamports: i64,
+
+    /// if set, the mutation is (also) paid for in this SPL token
+    pub price_mint: Option<Pubkey>,
+
+    /// denominated in price_mint's base units
+    pub price_amount: u64,
+
+    /// negative means refund, denominated in price_mint's base units
+    pub reversal_price_amount: i64,
+
+    /// applies to both price_lamports and price_amount (but not to reversals)
+    pub pricing_mode: PricingMode,
+}
+
+impl PriceConfig {
+    pub fn assert_is_valid(&self) -> Result<()> {
+        if self.price_mint.is_none() {
+            require!(
+                self.price_amount == 0 && self.reversal_price_amount == 0,
+                PriceMintMissing
+            );
+        }
+        self.pricing_mode.assert_is_valid()
+    }
+}
+
+#[proc_macros::assert_size(24)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct ReversalConfig {
+    /// how long after mutation_complete_ts the taker can still reverse, 0 = forever
+    pub window_sec: u64,
+
+    /// reversal price at the end of the window, as bps of the reversal price (interpolated linearly)
+    /// eg 0 = refund shrinks to nothing, 20_000 = fee doubles. Ignored if window_sec = 0
+    pub end_price_bps: u64,
+
+    /// how many times a single execution receipt can be reversed, 0 = unlimited
+    pub max_reversals: u64,
+}
+
+impl ReversalConfig {
+    pub fn assert_within_window(&self, mutation_complete_ts: u64, now_ts: u64) -> Result<()> {
+        if self.window_sec > 0 {
+            require!(
+                now_ts <= mutation_complete_ts.try_add(self.window_sec)?,
+                ReversalWindowClosed
+            );
+        }
+        Ok(())
+    }
+
+    pub fn assert_can_reverse_again(&self, reversal_count: u64) -> Result<()> {
+        if self.max_reversals > 0 {
+            require!(reversal_count < self.max_reversals, MaxReversalsReached);
+        }
+        Ok(())
+    }
+
+    /// works the same for refunds (negative) and fees (positive)
+    pub fn current_price(
+        &self,
+        base_price: i64,
+        mutation_complete_ts: u64,
+        now_ts: u64,
+    ) -> Result<i64> {
+        if self.window_sec == 0 {
+            return Ok(base_price);
+        }
+
+        let bps = BPS_DENOMINATOR as i128;
+        let window = self.window_sec as i128;
+        let elapsed = (now_ts.try_sub(mutation_complete_ts)? as i128).min(window);
+
+        // multiplier scaled up by window, to avoid rounding until the very end
+        let multiplier = bps.try_mul(window)?.try_add(
+            (self.end_price_bps as i128)
+                .try_sub(bps)?
+                .try_mul(elapsed)?,
+        )?;
+
+        (base_price as i128)
+            .try_mul(multiplier)?
+            .try_div(bps.try_mul(window)?)?
+            .try_cast()
+    }
+}
+
+/// All curves are expressed as a multiplier (in bps) on top of the base price,
+/// so that the same curve can be used for both lamport and token prices
+#[proc_macros::assert_size(32)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum PricingMode {
+    /// base price for the mutation's whole life
+    Fixed,
+    /// starts at start_price_bps of the base price and decays linearly to the base price at end_ts
+    DutchAuction {
+        start_ts: u64,
+        end_ts: u64,
+        start_price_bps: u64,
+    },
+    /// every use consumed adds step_bps of the base price
+    LinearUses { step_bps: u64 },
+    /// every use consumed raises the price by growth_bps, compounding
+    ExponentialUses { growth_bps: u64 },
+}
+
+impl PricingMode {
+    pub fn assert_is_valid(&self) -> Result<()> {
+        if let PricingMode::DutchAuction {
+            start_ts,
+            end_ts,
+            start_price_bps,
+        } = *self
+        {
+            require!(start_ts < end_ts, InvalidPricingMode);
+            require!(start_price_bps >= BPS_DENOMINATOR, InvalidPricingMode);
+        }
+        Ok(())
+    }
+
+    pub fn price_multiplier_bps(&self, now_ts: u64, uses_consumed: u64) -> Result<u64> {
+        match *self {
+            PricingMode::Fixed => Ok(BPS_DENOMINATOR),
+            PricingMode::DutchAuction {
+                start_ts,
+                end_ts,
+                start_price_bps,
+            } => {
+                if now_ts <= start_ts {
+                    return Ok(start_price_bps);
+                }
+                if now_ts >= end_ts {
+                    return Ok(BPS_DENOMINATOR);
+                }
+                let decay_bps = start_price_bps
+                    .try_sub(BPS_DENOMINATOR)?
+                    .try_mul(now_ts.try_sub(start_ts)?)?
+                    .try_div(end_ts.try_sub(start_ts)?)?;
+                start_price_bps.try_sub(decay_bps)
+            }
+            PricingMode::LinearUses { step_bps } => {
+                BPS_DENOMINATOR.try_add(step_bps.try_mul(uses_consumed)?)
+            }
+            PricingMode::ExponentialUses { growth_bps } => {
+                // exponentiation by squaring, rescaling to bps after every multiplication
+                let bps = BPS_DENOMINATOR as u128;
+                let mut multiplier = bps;
+                let mut base = bps.try_add(growth_bps as u128)?;
+                let mut exp = uses_consumed;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        multiplier = multiplier.try_mul(base)?.try_div(bps)?;
+                    }
+                    exp >>= 1;
+                    if exp > 0 {
+                        base = base.try_mul(base)?.try_div(bps)?;
+                    }
+                }
+                multiplier.try_cast()
+            }
+        }
+    }
+}
+
+pub const MAX_REVENUE_SPLITS: usize = 4;
+
+/// Share of every mutation payment sent to a single recipient
+#[proc_macros::assert_size(34)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct RevenueSplit {
+    pub recipient: Pubkey,
+
+    /// out of 10_000
+    pub share_bps: u16,
+}
+
+impl RevenueSplit {
+    /// either no splits at all, or up to 4 splits with non-zero shares adding up to 100%
+    pub fn assert_are_valid(splits: &[RevenueSplit]) -> Result<()> {
+        if splits.is_empty() {
+            return Ok(());
+        }
+        require!(splits.len() <= MAX_REVENUE_SPLITS, InvalidRevenueSplits);
+
+        let mut total_bps: u64 = 0;
+        for split in splits {
+            require!(split.share_bps > 0, InvalidRevenueSplits);
+            total_bps.try_add_assign(split.share_bps as u64)?;
+        }
+        require!(total_bps == BPS_DENOMINATOR, InvalidRevenueSplits);
+
+        Ok(())
+    }
+
+    /// rounds each share down and gives whatever is left over to the first recipient,
+    /// so that the shares always add up to exactly the amount paid
+    pub fn split_amount(splits: &[RevenueSplit], amount: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(splits.len());
+        let mut distributed: u64 = 0;
+
+        for split in splits {
+            let share = apply_bps(amount, split.share_bps as u64)?;
+            distributed.try_add_assign(share)?;
+            shares.push(share);
+        }
+
+        if let Some(first) = shares.first_mut() {
+            first.try_add_assign(amount.try_sub(distributed)?)?;
+        }
+
+        Ok(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(share_bps: u16) -> RevenueSplit {
+        RevenueSplit {
+            recipient: Pubkey::new_unique(),
+            share_bps,
+        }
+    }
+
+    #[test]
+    fn test_revenue_splits_validity() {
+        assert!(RevenueSplit::assert_are_valid(&[]).is_ok());
+        assert!(RevenueSplit::assert_are_valid(&[split(10_000)]).is_ok());
+        assert!(RevenueSplit::assert_are_valid(&[split(5000), split(3000), split(2000)]).is_ok());
+
+        // doesn't add up to 100%
+        assert!(RevenueSplit::assert_are_valid(&[split(5000), split(3000)]).is_err());
+        // zero share
+        assert!(RevenueSplit::assert_are_valid(&[split(10_000), split(0)]).is_err());
+        // too many
+        assert!(RevenueSplit::assert_are_valid(&[split(2000); 5]).is_err());
+    }
+
+    #[test]
+    fn test_split_amount() {
+        //the easy (no remainder) case
+        let shares = RevenueSplit::split_amount(&[split(5000), split(5000)], 100).unwrap();
+        assert_eq!(shares, vec![50, 50]);
+
+        //remainder goes to the first recipient
+        let splits = [split(3334), split(3333), split(3333)];
+        let shares = RevenueSplit::split_amount(&splits, 100).unwrap();
+        assert_eq!(shares, vec![34, 33, 33]);
+
+        let shares = RevenueSplit::split_amount(&splits, 1).unwrap();
+        assert_eq!(shares, vec![1, 0, 0]);
+
+        //no overflow on large amounts
+        let shares = RevenueSplit::split_amount(&[split(9999), split(1)], u64::MAX).unwrap();
+        assert_eq!(
+            shares.iter().map(|s| *s as u128).sum::<u128>(),
+            u64::MAX as u128
+        );
+    }
+
+    #[test]
+    fn test_reversal_price() {
+        let reversal = ReversalConfig {
+            window_sec: 100,
+            end_price_bps: 0,
+            max_reversals: 0,
+        };
+        // refund shrinks to nothing
+        assert_eq!(reversal.current_price(-1000, 50, 50).unwrap(), -1000);
+        assert_eq!(reversal.current_price(-1000, 50, 100).unwrap(), -500);
+        assert_eq!(reversal.current_price(-1000, 50, 150).unwrap(), 0);
+        assert_eq!(reversal.current_price(-1000, 50, 500).unwrap(), 0);
+
+        let reversal = ReversalConfig {
+            window_sec: 100,
+            end_price_bps: 20_000,
+            max_reversals: 0,
+        };
+        // fee doubles
+        assert_eq!(reversal.current_price(1000, 50, 75).unwrap(), 1250);
+        assert_eq!(reversal.current_price(1000, 50, 150).unwrap(), 2000);
+
+        // no window = flat price
+        let reversal = ReversalConfig {
+            window_sec: 0,
+            end_price_bps: 0,
+            max_reversals: 0,
+        };
+        assert_eq!(reversal.current_price(-1000, 50, 1_000_000).unwrap(), -1000);
+    }
+
+    #[test]
+    fn test_dutch_auction_multiplier() {
+        let mode = PricingMode::DutchAuction {
+            start_ts: 100,
+            end_ts: 200,
+            start_price_bps: 30_000,
+        };
+        assert_eq!(mode.price_multiplier_bps(50, 0).unwrap(), 30_000);
+        assert_eq!(mode.price_multiplier_bps(100, 0).unwrap(), 30_000);
+        assert_eq!(mode.price_multiplier_bps(150, 0).unwrap(), 20_000);
+        assert_eq!(mode.price_multiplier_bps(199, 0).unwrap(), 10_200);
+        assert_eq!(mode.price_multiplier_bps(200, 0).unwrap(), 10_000);
+        assert_eq!(mode.price_multiplier_bps(500, 0).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn test_uses_multipliers() {
+        let linear = PricingMode::LinearUses { step_bps: 500 };
+        assert_eq!(linear.price_multiplier_bps(0, 0).unwrap(), 10_000);
+        assert_eq!(linear.price_multiplier_bps(0, 4).unwrap(), 12_000);
+
+        let exponential = PricingMode::ExponentialUses { growth_bps: 10_000 };
+        assert_eq!(exponential.price_multiplier_bps(0, 0).unwrap(), 10_000);
+        assert_eq!(exponential.price_multiplier_bps(0, 1).unwrap(), 20_000);
+        assert_eq!(exponential.price_multiplier_bps(0, 5).unwrap(), 320_000);
+
+        let exponential = PricingMode::ExponentialUses { growth_bps: 1000 };
+        assert_eq!(exponential.price_multiplier_bps(0, 2).unwrap(), 12_100);
+
+        //too steep to price
+        assert!(exponential.price_multiplier_bps(0, 10_000).is_err());
+    }
+
+    #[test]
+    fn test_add_funding() {
+        let mut escrowed = MakerTokenConfig {
+            mint: Pubkey::new_unique(),
+            total_funding: 30,
+            amount_per_use: 10,
+            source: MakerTokenSource::Escrow,
+        };
+        assert_eq!(escrowed.add_funding(2).unwrap(), 20);
+        assert!(escrowed.assert_sufficient_funding(5).is_ok());
+
+        let mut minted = MakerTokenConfig {
+            source: MakerTokenSource::Mint,
+            total_funding: 0,
+            ..escrowed
+        };
+        assert_eq!(minted.add_funding(2).unwrap(), 0);
+        assert!(minted.assert_sufficient_funding(5).is_ok());
+    }
 }
@@ -0,0 +1,238 @@
+use crate::*;
+use mpl_token_metadata::state::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+use std::convert::TryInto;
+
+/// templates are stored raw after the header, name & uri padded with zeros
+pub const NFT_TEMPLATE_LEN: usize = MAX_NAME_LENGTH + MAX_URI_LENGTH;
+
+pub const NFT_TEMPLATE_LIST_HEADER_LEN: usize = 8 + std::mem::size_of::<NftTemplateList>();
+
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum NftRewardMode {
+    /// no nft reward
+    None,
+    /// templates are handed out in upload order
+    Sequential,
+    /// templates are picked at random from the ones not yet handed out
+    /// (revealed the same way as loot tables, so goes through pending)
+    Random,
+}
+
+/// Name + uri for a single nft reward, as uploaded by the maker
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct NftTemplate {
+    pub name: String,
+    pub uri: String,
+}
+
+/// Templates for NFTs minted as mutation rewards.
+/// Since the list can be large, the account is created by the maker (any size) and only the header
+/// is (de)serialized - templates are read / written directly from the bytes that follow it.
+#[proc_macros::assert_size(96)]
+#[repr(C)]
+#[account]
+pub struct NftTemplateList {
+    pub mutation: Pubkey,
+
+    /// shared by all the minted NFTs
+    pub symbol: [u8; 10],
+
+    pub seller_fee_basis_points: u16,
+
+    /// how many templates fit into the account
+    pub capacity: u32,
+
+    pub uploaded: u32,
+
+    /// templates [0, minted) have already been handed out
+    pub minted: u32,
+
+    _reserved: [u8; 40],
+}
+
+impl NftTemplateList {
+    pub fn capacity_for(data_len: usize) -> u32 {
+        (data_len.saturating_sub(NFT_TEMPLATE_LIST_HEADER_LEN) / NFT_TEMPLATE_LEN) as u32
+    }
+
+    pub fn available(&self) -> Result<u32> {
+        self.uploaded.try_sub(self.minted)
+    }
+
+    pub fn set_symbol(&mut self, symbol: &str) -> Result<()> {
+        require!(symbol.len() <= MAX_SYMBOL_LENGTH, InvalidNftReward);
+        self.symbol = [0; 10];
+        self.symbol[..symbol.len()].copy_from_slice(symbol.as_bytes());
+        Ok(())
+    }
+
+    pub fn symbol(&self) -> String {
+        String::from_utf8_lossy(&self.symbol)
+            .trim_matches(char::from(0))
+            .to_string()
+    }
+
+    pub fn try_append(&mut self, data: &mut [u8], templates: &[NftTemplate]) -> Result<()> {
+        require!(
+            self.uploaded as usize + templates.len() <= self.capacity as usize,
+            NftTemplatesExhausted
+        );
+        for template in templates {
+            require!(
+                template.name.len() <= MAX_NAME_LENGTH && template.uri.len() <= MAX_URI_LENGTH,
+                InvalidNftReward
+            );
+            let slot = Self::slot_mut(data, self.uploaded);
+            slot.fill(0);
+            slot[..template.name.len()].copy_from_slice(template.name.as_bytes());
+            slot[MAX_NAME_LENGTH..MAX_NAME_LENGTH + template.uri.len()]
+                .copy_from_slice(template.uri.as_bytes());
+            self.uploaded.try_add_assign(1)?;
+        }
+        Ok(())
+    }
+
+    /// picks the next template to hand out and marks it as minted.
+    /// Random picks are swapped into position `minted`, so that [0, minted) stays the handed out set.
+    /// Random picks without a seed (the reveal expired) take the next template in order, same as
+    /// Sequential - a fixed fallback rather than a fresh roll the taker could retry.
+    pub fn take_next(
+        &mut self,
+        data: &mut [u8],
+        mode: NftRewardMode,
+        seed: Option<[u8; 32]>,
+    ) -> Result<NftTemplate> {
+        let available = self.available()?;
+        require!(available > 0, NftTemplatesExhausted);
+
+        if let Some(seed) = seed.filter(|_| mode == NftRewardMode::Random) {
+            let offset: u32 = (u64::from_le_bytes(seed[..8].try_into().unwrap())
+                % available as u64)
+                .try_cast()?;
+            let picked = self.minted.try_add(offset)?;
+            if picked != self.minted {
+                let mut picked_template = [0; NFT_TEMPLATE_LEN];
+                picked_template.copy_from_slice(Self::slot_mut(data, picked));
+                let next = Self::slot_mut(data, self.minted).to_vec();
+                Self::slot_mut(data, picked).copy_from_slice(&next);
+                Self::slot_mut(data, self.minted).copy_from_slice(&picked_template);
+            }
+        }
+
+        let slot = Self::slot_mut(data, self.minted);
+        let template = NftTemplate {
+            name: Self::read_str(&slot[..MAX_NAME_LENGTH]),
+            uri: Self::read_str(&slot[MAX_NAME_LENGTH..]),
+        };
+        self.minted.try_add_assign(1)?;
+
+        Ok(template)
+    }
+
+    fn slot_mut(data: &mut [u8], index: u32) -> &mut [u8] {
+        let start = NFT_TEMPLATE_LIST_HEADER_LEN + index as usize * NFT_TEMPLATE_LEN;
+        &mut data[start..start + NFT_TEMPLATE_LEN]
+    }
+
+    fn read_str(bytes: &[u8]) -> String {
+        String::from_utf8_lossy(bytes)
+            .trim_matches(char::from(0))
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_with(names: &[&str]) -> (NftTemplateList, Vec<u8>) {
+        let mut data = vec![0; NFT_TEMPLATE_LIST_HEADER_LEN + names.len() * NFT_TEMPLATE_LEN];
+        let mut list = NftTemplateList {
+            mutation: Pubkey::default(),
+            symbol: [0; 10],
+            seller_fee_basis_points: 0,
+            capacity: NftTemplateList::capacity_for(data.len()),
+            uploaded: 0,
+            minted: 0,
+            _reserved: [0; 40],
+        };
+        let templates = names
+            .iter()
+            .map(|name| NftTemplate {
+                name: name.to_string(),
+                uri: format!("https://example.com/{}.json", name),
+            })
+            .collect::<Vec<_>>();
+        list.try_append(&mut data, &templates).unwrap();
+        (list, data)
+    }
+
+    #[test]
+    fn test_sequential() {
+        let (mut list, mut data) = list_with(&["a", "b"]);
+        assert_eq!(list.capacity, 2);
+
+        let first = list
+            .take_next(&mut data, NftRewardMode::Sequential, None)
+            .unwrap();
+        assert_eq!(first.name, "a");
+        assert_eq!(first.uri, "https://example.com/a.json");
+        let second = list
+            .take_next(&mut data, NftRewardMode::Sequential, None)
+            .unwrap();
+        assert_eq!(second.name, "b");
+        assert!(list
+            .take_next(&mut data, NftRewardMode::Sequential, None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_random_hands_out_each_template_once() {
+        let (mut list, mut data) = list_with(&["a", "b", "c"]);
+
+        let mut seed = [0; 32];
+        seed[0] = 2;
+        let first = list
+            .take_next(&mut data, NftRewardMode::Random, Some(seed))
+            .unwrap();
+        assert_eq!(first.name, "c");
+
+        let mut names = vec![first.name];
+        for _ in 0..2 {
+            let template = list
+                .take_next(&mut data, NftRewardMode::Random, Some(seed))
+                .unwrap();
+            names.push(template.name);
+        }
+        names.sort();
+        assert_eq!(names, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_random_expired_takes_next() {
+        let (mut list, mut data) = list_with(&["a", "b", "c"]);
+
+        let mut seed = [0; 32];
+        seed[0] = 2;
+        let first = list
+            .take_next(&mut data, NftRewardMode::Random, Some(seed))
+            .unwrap();
+        assert_eq!(first.name, "c");
+
+        // "c" swapped places with "a", so "b" is next in line
+        let expired = list
+            .take_next(&mut data, NftRewardMode::Random, None)
+            .unwrap();
+        assert_eq!(expired.name, "b");
+        assert_eq!(list.minted, 2);
+    }
+
+    #[test]
+    fn test_append_over_capacity() {
+        let (mut list, mut data) = list_with(&["a"]);
+        let extra = NftTemplate::default();
+        assert!(list.try_append(&mut data, &[extra]).is_err());
+    }
+}
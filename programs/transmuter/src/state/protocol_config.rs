@@ -0,0 +1,150 @@
+use crate::*;
+use anchor_lang::solana_program::account_info::next_account_info;
+
+pub const MAX_FEE_EXEMPT_WALLETS: usize = 8;
+
+/// Program-wide fee settings, a singleton pda with seeds [b"protocol_config"]
+/// Read by init_transmuter, init_mutation & execute / reverse, so fees can change without a redeploy
+#[proc_macros::assert_size(504)]
+#[repr(C)]
+#[account]
+pub struct ProtocolConfig {
+    /// can update this config (set to the upgrade authority on init)
+    pub admin: Pubkey,
+
+    pub transmuter_fee_lamports: u64,
+
+    pub mutation_fee_lamports: u64,
+
+    /// fees are split between these, filled from the front, unused entries have share_bps = 0
+    /// if empty, no fees are collected
+    pub fee_recipients: [RevenueSplit; 4],
+
+    /// partner wallets that don't pay any fees, Pubkey::default() if unused
+    pub fee_exempt: [Pubkey; 8],
+
+    /// receives execution_fee_bps of every lamport payment (incl. positive reversal fees)
+    pub execution_fee_recipient: Pubkey,
+
+    /// out of 10_000, 0 = no execution fee
+    pub execution_fee_bps: u16,
+
+    _reserved: [u8; 30],
+}
+
+impl ProtocolConfig {
+    pub fn set_fee_recipients(&mut self, fee_recipients: &[RevenueSplit]) -> Result<()> {
+        RevenueSplit::assert_are_valid(fee_recipients)?;
+        self.fee_recipients = Default::default();
+        for (i, recipient) in fee_recipients.iter().enumerate() {
+            self.fee_recipients[i] = *recipient;
+        }
+        Ok(())
+    }
+
+    pub fn active_fee_recipients(&self) -> &[RevenueSplit] {
+        let count = self
+            .fee_recipients
+            .iter()
+            .take_while(|recipient| recipient.share_bps > 0)
+            .count();
+        &self.fee_recipients[..count]
+    }
+
+    pub fn set_fee_exempt(&mut self, fee_exempt: &[Pubkey]) -> Result<()> {
+        require!(
+            fee_exempt.len() <= MAX_FEE_EXEMPT_WALLETS,
+            InvalidProtocolConfig
+        );
+        self.fee_exempt = Default::default();
+        for (i, wallet) in fee_exempt.iter().enumerate() {
+            self.fee_exempt[i] = *wallet;
+        }
+        Ok(())
+    }
+
+    pub fn is_fee_exempt(&self, wallet: Pubkey) -> bool {
+        wallet != Pubkey::default() && self.fee_exempt.contains(&wallet)
+    }
+
+    pub fn set_execution_fee(&mut self, recipient: Pubkey, fee_bps: u16) -> Result<()> {
+        require!(fee_bps as u64 <= BPS_DENOMINATOR, InvalidProtocolConfig);
+        self.execution_fee_recipient = recipient;
+        self.execution_fee_bps = fee_bps;
+        Ok(())
+    }
+
+    /// splits a transmuter / mutation creation fee between the recipients,
+    /// whose wallets are passed in protocol config order
+    pub fn collect_fee<'info>(
+        &self,
+        fee_lamports: u64,
+        fee_payer: Pubkey,
+        payer: &AccountInfo<'info>,
+        recipient_accounts: &mut std::slice::Iter<AccountInfo<'info>>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if self.is_fee_exempt(fee_payer) {
+            return Ok(());
+        }
+
+        let recipients = self.active_fee_recipients();
+        let shares = RevenueSplit::split_amount(recipients, fee_lamports)?;
+        for (recipient, share) in recipients.iter().zip(shares) {
+            let wallet = next_account_info(recipient_accounts)?;
+            assert_keys_eq!(wallet.key(), recipient.recipient, "fee recipient");
+            if share > 0 {
+                transfer_lamports(payer, wallet, system_program, share)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// protocol's cut of a lamport payment, taken out of what the maker receives
+    pub fn execution_fee(&self, lamports: u64, fee_payer: Pubkey) -> Result<u64> {
+        if self.is_fee_exempt(fee_payer) {
+            return Ok(0);
+        }
+        apply_bps(lamports, self.execution_fee_bps as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execution_fee() {
+        let partner = Pubkey::new_unique();
+        let mut config = ProtocolConfig {
+            admin: Pubkey::new_unique(),
+            transmuter_fee_lamports: 0,
+            mutation_fee_lamports: 0,
+            fee_recipients: Default::default(),
+            fee_exempt: Default::default(),
+            execution_fee_recipient: Pubkey::default(),
+            execution_fee_bps: 0,
+            _reserved: [0; 30],
+        };
+        config.set_fee_exempt(&[partner]).unwrap();
+
+        //off by default
+        assert_eq!(config.execution_fee(1000, Pubkey::new_unique()).unwrap(), 0);
+
+        config.set_execution_fee(Pubkey::new_unique(), 250).unwrap();
+        assert_eq!(
+            config.execution_fee(1000, Pubkey::new_unique()).unwrap(),
+            25
+        );
+        assert_eq!(config.execution_fee(1000, partner).unwrap(), 0);
+
+        //unused exempt slots don't exempt anyone
+        assert_eq!(config.execution_fee(1000, Pubkey::default()).unwrap(), 25);
+
+        //can't take more than the whole payment
+        assert!(config
+            .set_execution_fee(Pubkey::new_unique(), 10_001)
+            .is_err());
+    }
+}
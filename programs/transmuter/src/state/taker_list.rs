@@ -0,0 +1,188 @@
+use crate::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+// domain separation, so that an inner node can never be passed off as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum TakerListType {
+    /// anyone can execute
+    None,
+    /// leaves are hash(taker) - only wallets in the tree can execute
+    Allowlist,
+    /// leaves are hash(lower, upper) for every gap between two consecutive blocked wallets
+    /// (sorted, with Pubkey::default() and [255; 32] as the outer bounds)
+    /// a taker proves they're not blocked by proving they sit inside one of the gaps
+    Denylist,
+}
+
+#[proc_macros::assert_size(36)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct TakerList {
+    pub list_type: TakerListType,
+
+    /// merkle root, sorted pair hashing
+    pub root: [u8; 32],
+}
+
+/// Passed by the taker when starting an execution of a mutation with a taker list
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct TakerProof {
+    pub proof: Vec<[u8; 32]>,
+
+    /// denylists only - the bounds of the gap the taker sits in
+    pub lower_bound: Pubkey,
+    pub upper_bound: Pubkey,
+}
+
+impl TakerList {
+    pub fn assert_taker_allowed(
+        &self,
+        taker: Pubkey,
+        taker_proof: Option<TakerProof>,
+    ) -> Result<()> {
+        if self.list_type == TakerListType::None {
+            return Ok(());
+        }
+        let taker_proof = taker_proof.ok_or(ErrorCode::TakerNotAllowed)?;
+
+        let leaf = match self.list_type {
+            TakerListType::Allowlist => hash_leaf(&[taker.as_ref()]),
+            TakerListType::Denylist => {
+                require!(
+                    taker_proof.lower_bound < taker && taker < taker_proof.upper_bound,
+                    TakerNotAllowed
+                );
+                hash_leaf(&[
+                    taker_proof.lower_bound.as_ref(),
+                    taker_proof.upper_bound.as_ref(),
+                ])
+            }
+            TakerListType::None => unreachable!(),
+        };
+
+        require!(
+            verify_merkle_proof(&taker_proof.proof, self.root, leaf),
+            TakerNotAllowed
+        );
+
+        Ok(())
+    }
+}
+
+pub fn hash_leaf(data: &[&[u8]]) -> [u8; 32] {
+    let mut vals = vec![LEAF_PREFIX];
+    vals.extend_from_slice(data);
+    hashv(&vals).0
+}
+
+pub fn hash_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hashv(&[NODE_PREFIX, a, b]).0
+    } else {
+        hashv(&[NODE_PREFIX, b, a]).0
+    }
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |computed, node| hash_node(&computed, node));
+    computed == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowlist() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (leaf_a, leaf_b, leaf_c) = (
+            hash_leaf(&[a.as_ref()]),
+            hash_leaf(&[b.as_ref()]),
+            hash_leaf(&[c.as_ref()]),
+        );
+        let ab = hash_node(&leaf_a, &leaf_b);
+        let root = hash_node(&ab, &leaf_c);
+
+        let list = TakerList {
+            list_type: TakerListType::Allowlist,
+            root,
+        };
+        let proof = |proof: Vec<[u8; 32]>| {
+            Some(TakerProof {
+                proof,
+                ..Default::default()
+            })
+        };
+
+        assert!(list
+            .assert_taker_allowed(a, proof(vec![leaf_b, leaf_c]))
+            .is_ok());
+        assert!(list.assert_taker_allowed(c, proof(vec![ab])).is_ok());
+        assert!(list.assert_taker_allowed(c, proof(vec![leaf_a])).is_err());
+        assert!(list
+            .assert_taker_allowed(Pubkey::new_unique(), proof(vec![ab]))
+            .is_err());
+        assert!(list.assert_taker_allowed(a, None).is_err());
+    }
+
+    #[test]
+    fn test_denylist() {
+        let min = Pubkey::default();
+        let max = Pubkey::new_from_array([255; 32]);
+        let blocked = Pubkey::new_from_array([128; 32]);
+        let below = Pubkey::new_from_array([1; 32]);
+        let above = Pubkey::new_from_array([200; 32]);
+
+        let gap_low = hash_leaf(&[min.as_ref(), blocked.as_ref()]);
+        let gap_high = hash_leaf(&[blocked.as_ref(), max.as_ref()]);
+        let list = TakerList {
+            list_type: TakerListType::Denylist,
+            root: hash_node(&gap_low, &gap_high),
+        };
+
+        let proof = |lower_bound, upper_bound, node| {
+            Some(TakerProof {
+                proof: vec![node],
+                lower_bound,
+                upper_bound,
+            })
+        };
+
+        assert!(list
+            .assert_taker_allowed(below, proof(min, blocked, gap_high))
+            .is_ok());
+        assert!(list
+            .assert_taker_allowed(above, proof(blocked, max, gap_low))
+            .is_ok());
+        // taker has to sit strictly inside the gap
+        assert!(list
+            .assert_taker_allowed(blocked, proof(blocked, max, gap_low))
+            .is_err());
+        assert!(list
+            .assert_taker_allowed(above, proof(min, blocked, gap_high))
+            .is_err());
+        assert!(list.assert_taker_allowed(above, None).is_err());
+    }
+
+    #[test]
+    fn test_no_list() {
+        let list = TakerList {
+            list_type: TakerListType::None,
+            root: [0; 32],
+        };
+        assert!(list
+            .assert_taker_allowed(Pubkey::new_unique(), None)
+            .is_ok());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Tracks a single taker's executions of a single mutation, to enforce per-taker limits
+#[proc_macros::assert_size(112)]
+#[repr(C)]
+#[account]
+pub struct TakerRecord {
+    pub mutation: Pubkey,
+
+    pub taker: Pubkey,
+
+    /// started and not reversed
+    pub executions: u64,
+
+    pub last_execution_ts: u64,
+
+    _reserved: [u8; 32],
+}
+
+impl TakerRecord {
+    pub fn try_record_execution(&mut self, config: &MutationConfig, now_ts: u64) -> Result<()> {
+        if config.max_executions_per_taker > 0 {
+            require!(
+                self.executions < config.max_executions_per_taker,
+                TakerExecutionLimitReached
+            );
+        }
+        // last_execution_ts = 0 means the taker has never executed
+        if config.taker_cooldown_sec > 0 && self.last_execution_ts > 0 {
+            require!(
+                now_ts >= self.last_execution_ts.try_add(config.taker_cooldown_sec)?,
+                TakerCooldownActive
+            );
+        }
+
+        self.executions.try_add_assign(1)?;
+        self.last_execution_ts = now_ts;
+
+        Ok(())
+    }
+
+    /// a reversed execution no longer counts towards the limit (but still counts for the cooldown)
+    pub fn record_reversal(&mut self) {
+        self.executions = self.executions.saturating_sub(1);
+    }
+}
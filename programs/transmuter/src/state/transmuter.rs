@@ -1,8 +1,9 @@
 use crate::*;
 
-pub const LATEST_TRANSMUTER_VERSION: u16 = 0;
+/// 1 = laid out as below, 0 = LegacyTransmuter (has to go through migrate_transmuter)
+pub const LATEST_TRANSMUTER_VERSION: u16 = 1;
 
-#[proc_macros::assert_size(260)]
+#[proc_macros::assert_size(356)]
 #[repr(C)]
 #[account]
 pub struct Transmuter {
@@ -14,16 +15,131 @@ pub struct Transmuter {
     pub authority_seed: Pubkey,
     pub authority_bump_seed: [u8; 1],
 
-    // transmuter controls 3 banks - but not all 3 have to be used for each mutation
+    // transmuter controls up to 3 banks - but not all 3 have to be used for each mutation
+    // b & c are Pubkey::default() if skipped on init
     pub bank_a: Pubkey,
     pub bank_b: Pubkey,
     pub bank_c: Pubkey,
 
-    _reserved: [u8; 64],
+    /// added one by one via add_transmuter_bank, Pubkey::default() if not (yet) added
+    pub extra_banks: [Pubkey; 3],
+
+    /// if set, none of the transmuter's mutations can be started (pending ones can still complete)
+    pub paused: bool,
+
+    /// proposed via update_transmuter, only becomes owner once they accept
+    pub pending_owner: Option<Pubkey>,
+
+    /// live mutations, the transmuter can only be closed once they're all destroyed
+    /// (legacy mutations are counted as they're migrated)
+    pub mutation_count: u32,
+
+    /// legacy mutations not migrated yet, as declared by the owner on migrate_transmuter -
+    /// they can't be enumerated on chain, but still have to be migrated & destroyed before closing
+    pub unmigrated_mutations: u32,
+
+    /// bumped on every ownership change, roles granted under an older epoch no longer count
+    pub role_epoch: u32,
+
+    _reserved: [u8; 16],
+}
+
+/// how each of the banks is set up when the transmuter is created
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum BankSetup {
+    /// a fresh bank, its keypair has to sign
+    Create,
+    /// an existing bank whose manager has already been handed to the transmuter's authority
+    /// (keeps its whitelists & rarities)
+    Import,
+    /// not used, only allowed for banks b & c
+    Skip,
+}
+
+impl From<LegacyTransmuter> for Transmuter {
+    /// banks, owner & authority carry over, everything added since starts out empty
+    fn from(legacy: LegacyTransmuter) -> Self {
+        Transmuter {
+            version: LATEST_TRANSMUTER_VERSION,
+            owner: legacy.owner,
+            authority: legacy.authority,
+            authority_seed: legacy.authority_seed,
+            authority_bump_seed: legacy.authority_bump_seed,
+            bank_a: legacy.bank_a,
+            bank_b: legacy.bank_b,
+            bank_c: legacy.bank_c,
+            extra_banks: [Pubkey::default(); 3],
+            paused: false,
+            pending_owner: None,
+            mutation_count: 0,
+            unmigrated_mutations: 0,
+            role_epoch: 0,
+            _reserved: [0; 16],
+        }
+    }
 }
 
 impl Transmuter {
     pub fn get_seeds(&self) -> [&[u8]; 2] {
         [self.authority_seed.as_ref(), &self.authority_bump_seed]
     }
+
+    pub fn extra_bank_index(&self, bank: Pubkey) -> Option<usize> {
+        if bank == Pubkey::default() {
+            return None;
+        }
+        self.extra_banks
+            .iter()
+            .position(|extra_bank| *extra_bank == bank)
+    }
+
+    /// vaults are recorded on the receipt by the transmuter slot of their bank,
+    /// so each taker slot has to use the bank in the same transmuter slot
+    pub fn assert_taker_banks_match(&self, config: &MutationConfig) -> Result<()> {
+        config.taker_token_a.assert_correct_bank(self.bank_a)?;
+        if let Some(taker_token_b) = config.taker_token_b {
+            taker_token_b.assert_correct_bank(self.bank_b)?;
+        }
+        if let Some(taker_token_c) = config.taker_token_c {
+            taker_token_c.assert_correct_bank(self.bank_c)?;
+        }
+        for (taker_token, bank) in config
+            .extra_taker_tokens
+            .iter()
+            .zip(self.extra_banks.iter())
+        {
+            if let Some(taker_token) = taker_token {
+                taker_token.assert_correct_bank(*bank)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record_mutation_created(&mut self) -> Result<()> {
+        self.mutation_count.try_add_assign(1)
+    }
+
+    pub fn record_mutation_destroyed(&mut self) -> Result<()> {
+        self.mutation_count.try_sub_assign(1)
+    }
+
+    /// saturating, in case the owner under-declared their legacy mutations
+    pub fn record_mutation_migrated(&mut self) -> Result<()> {
+        self.unmigrated_mutations = self.unmigrated_mutations.saturating_sub(1);
+        self.record_mutation_created()
+    }
+
+    pub fn has_mutations(&self) -> bool {
+        self.mutation_count > 0 || self.unmigrated_mutations > 0
+    }
+
+    pub fn try_add_extra_bank(&mut self, bank: Pubkey) -> Result<()> {
+        let slot = self
+            .extra_banks
+            .iter_mut()
+            .find(|extra_bank| **extra_bank == Pubkey::default())
+            .ok_or(ErrorCode::NoMoreBankSlots)?;
+        *slot = bank;
+        Ok(())
+    }
 }
@@ -0,0 +1,59 @@
+use crate::*;
+
+/// Delegates part of the owner's admin rights to another wallet, so that the owner key can stay cold.
+/// One per (transmuter, member), pda with seeds [b"role", transmuter, member]
+#[proc_macros::assert_size(96)]
+#[repr(C)]
+#[account]
+pub struct TransmuterRole {
+    pub transmuter: Pubkey,
+
+    pub member: Pubkey,
+
+    /// can manage bank whitelists & rarities
+    pub curator: bool,
+
+    /// can create, update, pause & destroy mutations, and manage their taker lists & nft templates
+    pub mutation_manager: bool,
+
+    /// transmuter's role epoch when the roles were (last) set - an ownership transfer revokes them
+    pub role_epoch: u32,
+
+    _reserved: [u8; 24],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Role {
+    Curator,
+    MutationManager,
+}
+
+impl TransmuterRole {
+    pub fn grants(&self, role: Role) -> bool {
+        match role {
+            Role::Curator => self.curator,
+            Role::MutationManager => self.mutation_manager,
+        }
+    }
+
+    /// the owner can do anything, anyone else needs a role account granting them the role
+    /// (role account can be fake if the admin is the owner)
+    pub fn assert_admin_has_role(
+        transmuter: &Account<Transmuter>,
+        admin: Pubkey,
+        admin_role: &AccountInfo,
+        role: Role,
+    ) -> Result<()> {
+        if admin == transmuter.owner {
+            return Ok(());
+        }
+
+        let admin_role: Account<TransmuterRole> = Account::try_from(admin_role)?;
+        assert_keys_eq!(admin_role.transmuter, transmuter.key(), "role transmuter");
+        assert_keys_eq!(admin_role.member, admin, "role member");
+        require!(admin_role.role_epoch == transmuter.role_epoch, RoleRevoked);
+        require!(admin_role.grants(role), MissingRole);
+
+        Ok(())
+    }
+}
@@ -179,6 +179,12 @@ impl TryCast<u64> for u128 {
     }
 }
 
+impl TryCast<i64> for i128 {
+    fn try_cast(self) -> Result<i64> {
+        i64::try_from(self).map_err(|_| error!(ErrorCode::ArithmeticError))
+    }
+}
+
 impl TryCast<u32> for u64 {
     fn try_cast(self) -> Result<u32> {
         u32::try_from(self).map_err(|_| error!(ErrorCode::ArithmeticError))
@@ -1,17 +1,49 @@
 use std::convert::TryInto;
 use std::io::Write;
 
-use crate::{ErrorCode, TryAdd};
+use crate::{ErrorCode, TryAdd, TryCast, TryDiv, TryMul, TrySub};
 use anchor_lang::__private::CLOSED_ACCOUNT_DISCRIMINATOR;
-use anchor_lang::solana_program::program::invoke_signed;
-use anchor_lang::solana_program::system_instruction::create_account;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction::{create_account, transfer};
 use anchor_lang::{prelude::*, solana_program::clock};
 
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// amount * bps / 10_000, rounded down
+pub fn apply_bps(amount: u64, bps: u64) -> Result<u64> {
+    (amount as u128)
+        .try_mul(bps as u128)?
+        .try_div(BPS_DENOMINATOR as u128)?
+        .try_cast()
+}
+
 pub fn now_ts() -> Result<u64> {
     //i64 -> u64 ok to unwrap
     Ok(clock::Clock::get()?.unix_timestamp.try_into().unwrap())
 }
 
+pub fn transfer_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    invoke(
+        &transfer(from.key, to.key, lamports),
+        &[from.clone(), to.clone(), system_program.clone()],
+    )
+    .map_err(Into::into)
+}
+
+/// moves lamports out of an account owned by this program (e.g. a payment escrowed on a receipt) - no cpi needed
+pub fn withdraw_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> Result<()> {
+    let from_lamports = from.lamports().try_sub(lamports)?;
+    let to_lamports = to.lamports().try_add(lamports)?;
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
+
 pub fn create_pda_with_space<'info>(
     pda_seeds: &[&[u8]],
     pda_info: &AccountInfo<'info>,
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+
+# This script dumps the external programs loaded at genesis by `anchor test` (see Anchor.toml).
+# Binaries are gitignored, so `yarn test` runs this first - only missing ones are pulled.
+
+cd $(dirname $0)/..
+
+mkdir -p tests/programs/
+
+dump() {
+  if [ ! -f "tests/programs/$2" ]; then
+    solana program dump -u mainnet-beta $1 tests/programs/$2
+  fi
+}
+
+dump bankHHdqMuaaST4qQk6mkzxGeKPHWmqdgor6Gs8r88m gem_bank.so
+dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s mpl_token_metadata.so
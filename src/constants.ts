@@ -10,6 +10,11 @@ export type TransmuterTypes = AnchorTypes<
     transmuter: TransmuterData;
     mutation: MutationData;
     executionReceipt: ExecutionReceiptData;
+    takerRecord: TakerRecordData;
+    metadataTemplate: MetadataTemplateData;
+    nftTemplateList: NftTemplateListData;
+    transmuterRole: TransmuterRoleData;
+    protocolConfig: ProtocolConfigData;
   }
 >;
 
@@ -17,6 +22,11 @@ type Accounts = TransmuterTypes["Accounts"];
 export type TransmuterData = Accounts["Transmuter"];
 export type MutationData = Accounts["Mutation"];
 export type ExecutionReceiptData = Accounts["ExecutionReceipt"];
+export type TakerRecordData = Accounts["TakerRecord"];
+export type MetadataTemplateData = Accounts["MetadataTemplate"];
+export type NftTemplateListData = Accounts["NftTemplateList"];
+export type TransmuterRoleData = Accounts["TransmuterRole"];
+export type ProtocolConfigData = Accounts["ProtocolConfig"];
 
 export type TransmuterError = TransmuterTypes["Error"];
 export type TransmuterEvents = TransmuterTypes["Events"];
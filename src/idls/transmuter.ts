@@ -3,6 +3,88 @@ export type UtransmuterIDL =
   "version": "0.1.0",
   "name": "transmuter_v0",
   "instructions": [
+    {
+      "name": "initProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        },
+        {
+          "name": "transmuterFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "mutationFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "feeRecipients",
+          "type": {
+            "vec": {
+              "defined": "RevenueSplit"
+            }
+          }
+        },
+        {
+          "name": "feeExempt",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "executionFeeRecipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "executionFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
     {
       "name": "initTransmuter",
       "accounts": [
@@ -24,17 +106,17 @@ export type UtransmuterIDL =
         {
           "name": "bankA",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "bankB",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "bankC",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "gemBank",
@@ -47,13 +129,8 @@ export type UtransmuterIDL =
           "isSigner": true
         },
         {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc2",
-          "isMut": true,
+          "name": "protocolConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -66,6 +143,24 @@ export type UtransmuterIDL =
         {
           "name": "bumpAuth",
           "type": "u8"
+        },
+        {
+          "name": "bankASetup",
+          "type": {
+            "defined": "BankSetup"
+          }
+        },
+        {
+          "name": "bankBSetup",
+          "type": {
+            "defined": "BankSetup"
+          }
+        },
+        {
+          "name": "bankCSetup",
+          "type": {
+            "defined": "BankSetup"
+          }
         }
       ]
     },
@@ -91,62 +186,99 @@ export type UtransmuterIDL =
       ]
     },
     {
-      "name": "addToBankWhitelist",
+      "name": "acceptTransmuterOwner",
       "accounts": [
         {
           "name": "transmuter",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "newOwner",
+          "isMut": false,
           "isSigner": true
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransmuterOwner",
+      "accounts": [
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "transmuter",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTransmuterPaused",
+      "accounts": [
+        {
+          "name": "transmuter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "addressToWhitelist",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTransmuterRole",
+      "accounts": [
+        {
+          "name": "transmuter",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "transmuterRole",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
-          "type": "u8"
+          "name": "member",
+          "type": "publicKey"
         },
         {
-          "name": "whitelistType",
-          "type": "u8"
+          "name": "curator",
+          "type": "bool"
+        },
+        {
+          "name": "mutationManager",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "removeFromBankWhitelist",
+      "name": "revokeTransmuterRole",
       "accounts": [
         {
           "name": "transmuter",
@@ -159,22 +291,43 @@ export type UtransmuterIDL =
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "transmuterRole",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeTransmuter",
+      "accounts": [
         {
-          "name": "bank",
+          "name": "transmuter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "addressToRemove",
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "bankA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankC",
           "isMut": true,
           "isSigner": false
         },
@@ -190,22 +343,24 @@ export type UtransmuterIDL =
           "type": "u8"
         },
         {
-          "name": "bumpWl",
-          "type": "u8"
+          "name": "newBankManager",
+          "type": {
+            "option": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "addRaritiesToBank",
+      "name": "addTransmuterBank",
       "accounts": [
         {
           "name": "transmuter",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -215,14 +370,19 @@ export type UtransmuterIDL =
         },
         {
           "name": "bank",
-          "isMut": false,
-          "isSigner": false
+          "isMut": true,
+          "isSigner": true
         },
         {
           "name": "gemBank",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -233,19 +393,11 @@ export type UtransmuterIDL =
         {
           "name": "bumpAuth",
           "type": "u8"
-        },
-        {
-          "name": "rarityConfigs",
-          "type": {
-            "vec": {
-              "defined": "RarityConfig"
-            }
-          }
         }
       ]
     },
     {
-      "name": "initMutation",
+      "name": "addToBankWhitelist",
       "accounts": [
         {
           "name": "transmuter",
@@ -253,14 +405,14 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "mutation",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "adminRole",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "authority",
@@ -268,77 +420,87 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenAEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenASource",
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAMint",
+          "name": "addressToWhitelist",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBEscrow",
+          "name": "whitelistProof",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBSource",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBMint",
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
         },
         {
-          "name": "tokenCEscrow",
-          "isMut": true,
+          "name": "whitelistType",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromBankWhitelist",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenCSource",
+          "name": "owner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "feeAcc",
+          "name": "authority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeAcc2",
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "addressToRemove",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "whitelistProof",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
         }
@@ -349,47 +511,88 @@ export type UtransmuterIDL =
           "type": "u8"
         },
         {
-          "name": "bumpB",
+          "name": "bumpWl",
           "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addRaritiesToBank",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bumpC",
-          "type": "u8"
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "config",
-          "type": {
-            "defined": "MutationConfig"
-          }
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "uses",
-          "type": "u64"
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rarityConfigs",
+          "type": {
+            "vec": {
+              "defined": "RarityConfig"
+            }
+          }
         }
       ]
     },
     {
-      "name": "destroyMutation",
+      "name": "initMutation",
       "accounts": [
         {
           "name": "transmuter",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "mutation",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "authority",
           "isMut": false,
@@ -401,7 +604,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenADest",
+          "name": "tokenASource",
           "isMut": true,
           "isSigner": false
         },
@@ -416,7 +619,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenBDest",
+          "name": "tokenBSource",
           "isMut": true,
           "isSigner": false
         },
@@ -431,7 +634,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenCDest",
+          "name": "tokenCSource",
           "isMut": true,
           "isSigner": false
         },
@@ -441,12 +644,17 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -465,71 +673,45 @@ export type UtransmuterIDL =
         {
           "name": "bumpAuth",
           "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "initTakerVault",
-      "accounts": [
-        {
-          "name": "transmuter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mutation",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
+          "name": "bumpB",
+          "type": "u8"
         },
         {
-          "name": "creator",
-          "isMut": false,
-          "isSigner": false
+          "name": "bumpC",
+          "type": "u8"
         },
         {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
+          "name": "config",
+          "type": {
+            "defined": "MutationConfig"
+          }
         },
         {
-          "name": "taker",
-          "isMut": true,
-          "isSigner": true
+          "name": "uses",
+          "type": "u64"
         },
         {
-          "name": "executionReceipt",
-          "isMut": true,
-          "isSigner": false
+          "name": "name",
+          "type": "string"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpCreator",
-          "type": "u8"
+          "name": "revenueSplits",
+          "type": {
+            "vec": {
+              "defined": "RevenueSplit"
+            }
+          }
         }
       ]
     },
     {
-      "name": "executeMutation",
+      "name": "destroyMutation",
       "accounts": [
         {
           "name": "transmuter",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -543,42 +725,17 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bankA",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaultA",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bankB",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaultB",
+          "name": "admin",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "bankC",
+          "name": "adminRole",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultC",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
+          "name": "authority",
           "isMut": false,
           "isSigner": false
         },
@@ -588,7 +745,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenATakerAta",
+          "name": "tokenADest",
           "isMut": true,
           "isSigner": false
         },
@@ -603,7 +760,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenBTakerAta",
+          "name": "tokenBDest",
           "isMut": true,
           "isSigner": false
         },
@@ -618,7 +775,7 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenCTakerAta",
+          "name": "tokenCDest",
           "isMut": true,
           "isSigner": false
         },
@@ -628,12 +785,12 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "taker",
+          "name": "metadataTemplate",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "executionReceipt",
+          "name": "nftTemplateList",
           "isMut": true,
           "isSigner": false
         },
@@ -658,10 +815,15 @@ export type UtransmuterIDL =
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "reverseMutation",
+      "name": "fundMutation",
       "accounts": [
         {
           "name": "transmuter",
@@ -674,105 +836,318 @@ export type UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "tokenAEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "tokenASource",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bankA",
-          "isMut": false,
+          "name": "tokenBEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vaultA",
+          "name": "tokenBSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bankB",
-          "isMut": false,
+          "name": "tokenCEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vaultB",
+          "name": "tokenCSource",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bankC",
+          "name": "sponsor",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "vaultC",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "gemBank",
+          "name": "uses",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAEscrow",
+          "name": "mutation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenATakerAta",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "tokenAMint",
+          "name": "adminRole",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": {
+            "defined": "PriceConfig"
+          }
         },
         {
-          "name": "tokenBEscrow",
-          "isMut": true,
+          "name": "mutationDurationSec",
+          "type": "u64"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setMutationPaused",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBTakerAta",
+          "name": "mutation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBMint",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTakerList",
+      "accounts": [
+        {
+          "name": "transmuter",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenCEscrow",
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "takerList",
+          "type": {
+            "defined": "TakerList"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMetadataTemplate",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenCTakerAta",
+          "name": "metadataTemplate",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": {
+            "option": "string"
+          }
         },
         {
-          "name": "taker",
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initNftTemplateList",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "sellerFeeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "forceCancelMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "executionReceipt",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -795,512 +1170,124 @@ export type UtransmuterIDL =
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "ExecutionReceipt",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "transmuter",
-            "type": "publicKey"
-          },
-          {
-            "name": "mutation",
-            "type": "publicKey"
-          },
-          {
-            "name": "taker",
-            "type": "publicKey"
-          },
-          {
-            "name": "mutationCompleteTs",
-            "type": "u64"
-          },
-          {
-            "name": "state",
-            "type": {
-              "defined": "ExecutionState"
-            }
-          },
-          {
-            "name": "vaultA",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "vaultB",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "vaultC",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
     },
     {
-      "name": "Mutation",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "transmuter",
-            "type": "publicKey"
-          },
-          {
-            "name": "config",
-            "type": {
-              "defined": "MutationConfig"
-            }
-          },
-          {
-            "name": "tokenAEscrow",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenBEscrow",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "tokenCEscrow",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "totalUses",
-            "type": "u64"
-          },
-          {
-            "name": "remainingUses",
-            "type": "u64"
-          },
-          {
-            "name": "state",
-            "type": {
-              "defined": "MutationState"
-            }
-          },
-          {
-            "name": "name",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
+      "name": "addNftTemplates",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "templates",
+          "type": {
+            "vec": {
+              "defined": "NftTemplate"
             }
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "Transmuter",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "authoritySeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "authorityBumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "bankA",
-            "type": "publicKey"
-          },
-          {
-            "name": "bankB",
-            "type": "publicKey"
-          },
-          {
-            "name": "bankC",
-            "type": "publicKey"
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                64
-              ]
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "RarityConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "rarityPoints",
-            "type": "u16"
-          }
-        ]
-      }
+      "name": "initTakerVault",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCreator",
+          "type": "u8"
+        },
+        {
+          "name": "nonce",
+          "type": "u32"
+        }
+      ]
     },
     {
-      "name": "MutationConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "takerTokenA",
-            "type": {
-              "defined": "TakerTokenConfig"
-            }
-          },
-          {
-            "name": "takerTokenB",
-            "type": {
-              "option": {
-                "defined": "TakerTokenConfig"
-              }
-            }
-          },
-          {
-            "name": "takerTokenC",
-            "type": {
-              "option": {
-                "defined": "TakerTokenConfig"
-              }
-            }
-          },
-          {
-            "name": "makerTokenA",
-            "type": {
-              "defined": "MakerTokenConfig"
-            }
-          },
-          {
-            "name": "makerTokenB",
-            "type": {
-              "option": {
-                "defined": "MakerTokenConfig"
-              }
-            }
-          },
-          {
-            "name": "makerTokenC",
-            "type": {
-              "option": {
-                "defined": "MakerTokenConfig"
-              }
-            }
-          },
-          {
-            "name": "price",
-            "type": {
-              "defined": "PriceConfig"
-            }
-          },
-          {
-            "name": "mutationDurationSec",
-            "type": "u64"
-          },
-          {
-            "name": "reversible",
-            "type": "bool"
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "TakerTokenConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "gemBank",
-            "type": "publicKey"
-          },
-          {
-            "name": "requiredAmount",
-            "type": "u64"
-          },
-          {
-            "name": "requiredUnits",
-            "type": {
-              "defined": "RequiredUnits"
-            }
-          },
-          {
-            "name": "vaultAction",
-            "type": {
-              "defined": "VaultAction"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "MakerTokenConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "totalFunding",
-            "type": "u64"
-          },
-          {
-            "name": "amountPerUse",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "PriceConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "priceLamports",
-            "type": "u64"
-          },
-          {
-            "name": "reversalPriceLamports",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ExecutionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "NotStarted"
-          },
-          {
-            "name": "Pending"
-          },
-          {
-            "name": "Complete"
-          }
-        ]
-      }
-    },
-    {
-      "name": "MutationState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Available"
-          },
-          {
-            "name": "Exhausted"
-          }
-        ]
-      }
-    },
-    {
-      "name": "RequiredUnits",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "RarityPoints"
-          },
-          {
-            "name": "Gems"
-          }
-        ]
-      }
-    },
-    {
-      "name": "VaultAction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "ChangeOwner"
-          },
-          {
-            "name": "Lock"
-          },
-          {
-            "name": "DoNothing"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidTokenIndex",
-      "msg": "Token index must be one of 1,2,3"
-    },
-    {
-      "code": 6001,
-      "name": "BankDoesNotMatch",
-      "msg": "Bank account passed != bank account in config"
-    },
-    {
-      "code": 6002,
-      "name": "MintDoesNotMatch",
-      "msg": "Mint account passed != mint account in config"
-    },
-    {
-      "code": 6003,
-      "name": "CandyMachineMissing",
-      "msg": "Minted mutations require a Candy Machine ID to be present"
-    },
-    {
-      "code": 6004,
-      "name": "ArithmeticError",
-      "msg": "Arithmetic error (likely under/overflow)"
-    },
-    {
-      "code": 6005,
-      "name": "NoMoreUsesLeft",
-      "msg": "This mutation has exhausted all of its uses"
-    },
-    {
-      "code": 6006,
-      "name": "IncorrectFunding",
-      "msg": "Funding amount doesn't added up to uses * amount per use"
-    },
-    {
-      "code": 6007,
-      "name": "InsufficientVaultGems",
-      "msg": "This taker's vault doesn't have enough gems"
-    },
-    {
-      "code": 6008,
-      "name": "InsufficientVaultRarityPoints",
-      "msg": "This taker's vault doesn't have enough rarity points"
-    },
-    {
-      "code": 6009,
-      "name": "VaultDoesNotBelongToBank",
-      "msg": "Passed vault doesn't belong to passed bank"
-    },
-    {
-      "code": 6010,
-      "name": "VaultsNotSetToLock",
-      "msg": "Reversals require all vaults to be set to Lock"
-    },
-    {
-      "code": 6011,
-      "name": "MutationNotComplete",
-      "msg": "Mutation execution hasn't completed yet"
-    },
-    {
-      "code": 6012,
-      "name": "MutationAlreadyComplete",
-      "msg": "Mutation execution already finished in the past"
-    },
-    {
-      "code": 6013,
-      "name": "MutationNotReversible",
-      "msg": "Mutation isn't configured to be reversible"
-    },
-    {
-      "code": 6014,
-      "name": "AnchorSerializationIssue",
-      "msg": "Anchor serialization issue"
-    },
-    {
-      "code": 6015,
-      "name": "NoneOfTheBanksMatch",
-      "msg": "Trying to init a vault for an unknown bank"
-    },
-    {
-      "code": 6016,
-      "name": "AccountDiscriminatorMismatch",
-      "msg": "Account discriminator mismatch"
-    }
-  ]
-}
-;
-export const UtransmuterJSON: UtransmuterIDL =
-{
-  "version": "0.1.0",
-  "name": "transmuter_v0",
-  "instructions": [
-    {
-      "name": "initTransmuter",
+      "name": "executeMutation",
       "accounts": [
         {
           "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "authority",
@@ -1309,190 +1296,176 @@ export const UtransmuterJSON: UtransmuterIDL =
         },
         {
           "name": "bankA",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bankB",
+          "name": "vaultA",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "bankC",
-          "isMut": true,
-          "isSigner": true
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "metadataTemplate",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "feeAcc",
+          "name": "tokenATakerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeAcc2",
+          "name": "tokenAMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "priceMint",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "updateTransmuter",
-      "accounts": [
+        },
         {
-          "name": "transmuter",
+          "name": "priceTakerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "priceOwnerAta",
           "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "newOwner",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "addToBankWhitelist",
-      "accounts": [
-        {
-          "name": "transmuter",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "priceEscrow",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "protocolFeeRecipient",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "addressToWhitelist",
-          "isMut": false,
+          "name": "nftTemplateList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "nftMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "nftTakerAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
-          "isMut": false,
+          "name": "nftMetadata",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
         },
         {
-          "name": "whitelistType",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "removeFromBankWhitelist",
-      "accounts": [
-        {
-          "name": "transmuter",
-          "isMut": false,
+          "name": "nftMasterEdition",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "taker",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "authority",
+          "name": "executionReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "takerRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "addressToRemove",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
-          "isMut": true,
+          "name": "associatedTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
-          "type": "u8"
+          "name": "takerProof",
+          "type": {
+            "option": {
+              "defined": "TakerProof"
+            }
+          }
         },
         {
-          "name": "bumpWl",
-          "type": "u8"
+          "name": "gemBumps",
+          "type": {
+            "vec": {
+              "defined": "GemBoxBumps"
+            }
+          }
         }
       ]
     },
     {
-      "name": "addRaritiesToBank",
+      "name": "reverseMutation",
       "accounts": [
         {
           "name": "transmuter",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "owner",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "authority",
@@ -1500,116 +1473,112 @@ export const UtransmuterJSON: UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "bankA",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "gemBank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "metadataTemplate",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "bumpAuth",
-          "type": "u8"
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "rarityConfigs",
-          "type": {
-            "vec": {
-              "defined": "RarityConfig"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "initMutation",
-      "accounts": [
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "transmuter",
-          "isMut": false,
+          "name": "tokenATakerAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mutation",
+          "name": "tokenAMint",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "priceMint",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "priceTakerAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAEscrow",
+          "name": "priceOwnerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenASource",
+          "name": "priceEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAMint",
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBEscrow",
+          "name": "protocolFeeRecipient",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBSource",
+          "name": "nftTemplateList",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBMint",
-          "isMut": false,
+          "name": "nftMint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCEscrow",
+          "name": "nftTakerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCSource",
+          "name": "nftMetadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
-          "isMut": false,
+          "name": "nftMasterEdition",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "taker",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "feeAcc",
+          "name": "executionReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeAcc2",
+          "name": "takerRecord",
           "isMut": true,
           "isSigner": false
         },
@@ -1619,47 +1588,30 @@ export const UtransmuterJSON: UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpB",
-          "type": "u8"
-        },
-        {
-          "name": "bumpC",
-          "type": "u8"
-        },
-        {
-          "name": "config",
-          "type": {
-            "defined": "MutationConfig"
-          }
         },
         {
-          "name": "uses",
-          "type": "u64"
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "destroyMutation",
+      "name": "cancelMutation",
       "accounts": [
         {
           "name": "transmuter",
@@ -1673,8 +1625,8 @@ export const UtransmuterJSON: UtransmuterIDL =
         },
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "authority",
@@ -1682,109 +1634,98 @@ export const UtransmuterJSON: UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "tokenAEscrow",
-          "isMut": true,
+          "name": "bankA",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenADest",
+          "name": "vaultA",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAMint",
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBEscrow",
-          "isMut": true,
+          "name": "metadataTemplate",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBDest",
-          "isMut": true,
+          "name": "tokenMetadataProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBMint",
-          "isMut": false,
+          "name": "tokenAEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCEscrow",
+          "name": "tokenATakerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCDest",
+          "name": "tokenAMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
+          "name": "priceMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "priceTakerAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "priceOwnerAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "priceEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "initTakerVault",
-      "accounts": [
+        },
         {
-          "name": "transmuter",
-          "isMut": false,
+          "name": "protocolFeeRecipient",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mutation",
-          "isMut": false,
+          "name": "nftTemplateList",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "nftMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "nftTakerAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "creator",
-          "isMut": false,
+          "name": "nftMetadata",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
-          "isMut": false,
+          "name": "nftMasterEdition",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1798,286 +1739,143 @@ export const UtransmuterJSON: UtransmuterIDL =
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpCreator",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "executeMutation",
-      "accounts": [
-        {
-          "name": "transmuter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mutation",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bankA",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaultA",
+          "name": "takerRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bankB",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultB",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bankC",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vaultC",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenAEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenATakerAta",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenAMint",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBTakerAta",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBMint",
+          "name": "slotHashes",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "tokenCEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExecutionReceipt",
+      "accounts": [
         {
-          "name": "tokenCTakerAta",
+          "name": "executionReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
+          "name": "mutation",
           "isMut": false,
           "isSigner": false
         },
         {
           "name": "taker",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "executionReceipt",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
+          "name": "closer",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": []
     },
     {
-      "name": "reverseMutation",
+      "name": "migrateTransmuter",
       "accounts": [
         {
           "name": "transmuter",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mutation",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bankA",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaultA",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bankB",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vaultB",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bankC",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "vaultC",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "gemBank",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenAEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "legacyMutationCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migrateMutation",
+      "accounts": [
         {
-          "name": "tokenATakerAta",
+          "name": "transmuter",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenAMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBEscrow",
+          "name": "mutation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBTakerAta",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenBMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateExecutionReceipt",
+      "accounts": [
         {
-          "name": "tokenCEscrow",
+          "name": "mutation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCTakerAta",
+          "name": "executionReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenCMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "taker",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
-        {
-          "name": "executionReceipt",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": []
@@ -2085,56 +1883,4076 @@ export const UtransmuterJSON: UtransmuterIDL =
   ],
   "accounts": [
     {
-      "name": "ExecutionReceipt",
+      "name": "ExecutionReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutationCompleteTs",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ExecutionState"
+            }
+          },
+          {
+            "name": "vaultA",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultB",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultC",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "extraVaults",
+            "type": {
+              "array": [
+                {
+                  "option": "publicKey"
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "reversalCount",
+            "type": "u64"
+          },
+          {
+            "name": "commitSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lootOutcome",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "escrowedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "escrowedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataTemplate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mutation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "MutationConfig"
+            }
+          },
+          {
+            "name": "tokenAEscrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "tokenCEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "extraEscrows",
+            "type": {
+              "array": [
+                {
+                  "option": "publicKey"
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "totalUses",
+            "type": "u64"
+          },
+          {
+            "name": "remainingUses",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "MutationState"
+            }
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revenueSplits",
+            "type": {
+              "array": [
+                {
+                  "defined": "RevenueSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingExecutions",
+            "type": "u64"
+          },
+          {
+            "name": "takerList",
+            "type": {
+              "defined": "TakerList"
+            }
+          },
+          {
+            "name": "nftTemplateList",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                19
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftTemplateList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "array": [
+                "u8",
+                10
+              ]
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "uploaded",
+            "type": "u32"
+          },
+          {
+            "name": "minted",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                40
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProtocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "transmuterFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "mutationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "feeRecipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "RevenueSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeExempt",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "executionFeeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "executionFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                30
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "executions",
+            "type": "u64"
+          },
+          {
+            "name": "lastExecutionTs",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Transmuter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "authoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "bankA",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankB",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankC",
+            "type": "publicKey"
+          },
+          {
+            "name": "extraBanks",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mutationCount",
+            "type": "u32"
+          },
+          {
+            "name": "unmigratedMutations",
+            "type": "u32"
+          },
+          {
+            "name": "roleEpoch",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransmuterRole",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "curator",
+            "type": "bool"
+          },
+          {
+            "name": "mutationManager",
+            "type": "bool"
+          },
+          {
+            "name": "roleEpoch",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                24
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RarityConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GemBoxBumps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gemBox",
+            "type": "u8"
+          },
+          {
+            "name": "gemDepositReceipt",
+            "type": "u8"
+          },
+          {
+            "name": "gemRarity",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyTransmuter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "authoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "bankA",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankB",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankC",
+            "type": "publicKey"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMutation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "LegacyMutationConfig"
+            }
+          },
+          {
+            "name": "tokenAEscrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "tokenCEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "totalUses",
+            "type": "u64"
+          },
+          {
+            "name": "remainingUses",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "MutationState"
+            }
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMutationConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerTokenA",
+            "type": {
+              "defined": "TakerTokenConfig"
+            }
+          },
+          {
+            "name": "takerTokenB",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "takerTokenC",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "makerTokenA",
+            "type": {
+              "defined": "LegacyMakerTokenConfig"
+            }
+          },
+          {
+            "name": "makerTokenB",
+            "type": {
+              "option": {
+                "defined": "LegacyMakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "makerTokenC",
+            "type": {
+              "option": {
+                "defined": "LegacyMakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "LegacyPriceConfig"
+            }
+          },
+          {
+            "name": "mutationDurationSec",
+            "type": "u64"
+          },
+          {
+            "name": "reversible",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMakerTokenConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFunding",
+            "type": "u64"
+          },
+          {
+            "name": "amountPerUse",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyPriceConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceLamports",
+            "type": "u64"
+          },
+          {
+            "name": "reversalPriceLamports",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyExecutionReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutationCompleteTs",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ExecutionState"
+            }
+          },
+          {
+            "name": "vaultA",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultB",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultC",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LootTable",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "weights",
+            "type": {
+              "array": [
+                "u16",
+                5
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MutationConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "takerTokenA",
+            "type": {
+              "defined": "TakerTokenConfig"
+            }
+          },
+          {
+            "name": "takerTokenB",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "takerTokenC",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "makerTokenA",
+            "type": {
+              "defined": "MakerTokenConfig"
+            }
+          },
+          {
+            "name": "makerTokenB",
+            "type": {
+              "option": {
+                "defined": "MakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "makerTokenC",
+            "type": {
+              "option": {
+                "defined": "MakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "extraTakerTokens",
+            "type": {
+              "array": [
+                {
+                  "option": {
+                    "defined": "TakerTokenConfig"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "extraMakerTokens",
+            "type": {
+              "array": [
+                {
+                  "option": {
+                    "defined": "MakerTokenConfig"
+                  }
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "PriceConfig"
+            }
+          },
+          {
+            "name": "mutationDurationSec",
+            "type": "u64"
+          },
+          {
+            "name": "reversible",
+            "type": "bool"
+          },
+          {
+            "name": "reversal",
+            "type": {
+              "defined": "ReversalConfig"
+            }
+          },
+          {
+            "name": "startTs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "endTs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxExecutionsPerTaker",
+            "type": "u64"
+          },
+          {
+            "name": "takerCooldownSec",
+            "type": "u64"
+          },
+          {
+            "name": "lootTable",
+            "type": {
+              "defined": "LootTable"
+            }
+          },
+          {
+            "name": "nftReward",
+            "type": {
+              "defined": "NftRewardMode"
+            }
+          },
+          {
+            "name": "cancellationPenaltyBps",
+            "type": "u16"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerTokenConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gemBank",
+            "type": "publicKey"
+          },
+          {
+            "name": "requiredAmount",
+            "type": "u64"
+          },
+          {
+            "name": "requiredUnits",
+            "type": {
+              "defined": "RequiredUnits"
+            }
+          },
+          {
+            "name": "vaultAction",
+            "type": {
+              "defined": "VaultAction"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MakerTokenConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFunding",
+            "type": "u64"
+          },
+          {
+            "name": "amountPerUse",
+            "type": "u64"
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": "MakerTokenSource"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceLamports",
+            "type": "u64"
+          },
+          {
+            "name": "reversalPriceLamports",
+            "type": "i64"
+          },
+          {
+            "name": "priceMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "priceAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reversalPriceAmount",
+            "type": "i64"
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "defined": "PricingMode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReversalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSec",
+            "type": "u64"
+          },
+          {
+            "name": "endPriceBps",
+            "type": "u64"
+          },
+          {
+            "name": "maxReversals",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevenueSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftTemplate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "listType",
+            "type": {
+              "defined": "TakerListType"
+            }
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "lowerBound",
+            "type": "publicKey"
+          },
+          {
+            "name": "upperBound",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExecutionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotStarted"
+          },
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Complete"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MutationState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Available"
+          },
+          {
+            "name": "Exhausted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RequiredUnits",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RarityPoints"
+          },
+          {
+            "name": "Gems"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MakerTokenSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Escrow"
+          },
+          {
+            "name": "Mint"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ChangeOwner"
+          },
+          {
+            "name": "Lock"
+          },
+          {
+            "name": "DoNothing"
+          },
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "UpdateMetadata"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PricingMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "DutchAuction",
+            "fields": [
+              {
+                "name": "startTs",
+                "type": "u64"
+              },
+              {
+                "name": "endTs",
+                "type": "u64"
+              },
+              {
+                "name": "startPriceBps",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "LinearUses",
+            "fields": [
+              {
+                "name": "stepBps",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "ExponentialUses",
+            "fields": [
+              {
+                "name": "growthBps",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftRewardMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Sequential"
+          },
+          {
+            "name": "Random"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerListType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Allowlist"
+          },
+          {
+            "name": "Denylist"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BankSetup",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Create"
+          },
+          {
+            "name": "Import"
+          },
+          {
+            "name": "Skip"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidTokenIndex",
+      "msg": "Token index must be one of 1,2,3"
+    },
+    {
+      "code": 6001,
+      "name": "BankDoesNotMatch",
+      "msg": "Bank account passed != bank account in config"
+    },
+    {
+      "code": 6002,
+      "name": "MintDoesNotMatch",
+      "msg": "Mint account passed != mint account in config"
+    },
+    {
+      "code": 6003,
+      "name": "MintAuthorityMissing",
+      "msg": "Minted rewards require the transmuter authority to be the mint authority"
+    },
+    {
+      "code": 6004,
+      "name": "ArithmeticError",
+      "msg": "Arithmetic error (likely under/overflow)"
+    },
+    {
+      "code": 6005,
+      "name": "NoMoreUsesLeft",
+      "msg": "This mutation has exhausted all of its uses"
+    },
+    {
+      "code": 6006,
+      "name": "IncorrectFunding",
+      "msg": "Funding amount doesn't added up to uses * amount per use"
+    },
+    {
+      "code": 6007,
+      "name": "InsufficientVaultGems",
+      "msg": "This taker's vault doesn't have enough gems"
+    },
+    {
+      "code": 6008,
+      "name": "InsufficientVaultRarityPoints",
+      "msg": "This taker's vault doesn't have enough rarity points"
+    },
+    {
+      "code": 6009,
+      "name": "VaultDoesNotBelongToBank",
+      "msg": "Passed vault doesn't belong to passed bank"
+    },
+    {
+      "code": 6010,
+      "name": "VaultsNotSetToLock",
+      "msg": "Reversals require all vaults to be set to Lock"
+    },
+    {
+      "code": 6011,
+      "name": "MutationNotComplete",
+      "msg": "Mutation execution hasn't completed yet"
+    },
+    {
+      "code": 6012,
+      "name": "MutationAlreadyComplete",
+      "msg": "Mutation execution already finished in the past"
+    },
+    {
+      "code": 6013,
+      "name": "MutationNotReversible",
+      "msg": "Mutation isn't configured to be reversible"
+    },
+    {
+      "code": 6014,
+      "name": "AnchorSerializationIssue",
+      "msg": "Anchor serialization issue"
+    },
+    {
+      "code": 6015,
+      "name": "NoneOfTheBanksMatch",
+      "msg": "Trying to init a vault for an unknown bank"
+    },
+    {
+      "code": 6016,
+      "name": "AccountDiscriminatorMismatch",
+      "msg": "Account discriminator mismatch"
+    },
+    {
+      "code": 6017,
+      "name": "PriceMintMissing",
+      "msg": "Token prices require a price mint to be set"
+    },
+    {
+      "code": 6018,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account has already been migrated to the latest layout"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRevenueSplits",
+      "msg": "Revenue splits must have non-zero shares adding up to 10000 bps (max 4)"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidPricingMode",
+      "msg": "Dutch auctions need start_ts < end_ts and a start price >= the base price"
+    },
+    {
+      "code": 6021,
+      "name": "ReversalWindowClosed",
+      "msg": "The window for reversing this mutation has closed"
+    },
+    {
+      "code": 6022,
+      "name": "MaxReversalsReached",
+      "msg": "This execution has already been reversed the maximum number of times"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidAvailabilityWindow",
+      "msg": "Mutation start_ts must be before end_ts"
+    },
+    {
+      "code": 6024,
+      "name": "MutationNotYetAvailable",
+      "msg": "This mutation isn't available yet (before start_ts)"
+    },
+    {
+      "code": 6025,
+      "name": "MutationNoLongerAvailable",
+      "msg": "This mutation is no longer available (after end_ts)"
+    },
+    {
+      "code": 6026,
+      "name": "MutationHasPendingExecutions",
+      "msg": "Mutation has executions pending completion"
+    },
+    {
+      "code": 6027,
+      "name": "TakerExecutionLimitReached",
+      "msg": "This taker has reached the max number of executions for this mutation"
+    },
+    {
+      "code": 6028,
+      "name": "TakerCooldownActive",
+      "msg": "This taker has to wait for the cooldown to pass before executing again"
+    },
+    {
+      "code": 6029,
+      "name": "TakerNotAllowed",
+      "msg": "This taker isn't allowed to execute this mutation (invalid allow/denylist proof)"
+    },
+    {
+      "code": 6030,
+      "name": "NoMoreBankSlots",
+      "msg": "Transmuter already has the max number of banks"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidLootTable",
+      "msg": "Loot table weights must only be set on maker slots in use, on a short, non-reversible mutation"
+    },
+    {
+      "code": 6032,
+      "name": "LootNotYetRevealable",
+      "msg": "Loot roll can only be revealed in a later slot than the one it was committed in"
+    },
+    {
+      "code": 6033,
+      "name": "VaultNotFullyBurned",
+      "msg": "Not all gems in the vault were burned, pass accounts for every gem box"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMetadataTemplate",
+      "msg": "Metadata template name / uri are too long (or uri is empty)"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidNftReward",
+      "msg": "Invalid nft reward config or template (too long, reversible, or list already set)"
+    },
+    {
+      "code": 6036,
+      "name": "NftTemplatesExhausted",
+      "msg": "Not enough nft templates left for this execution"
+    },
+    {
+      "code": 6037,
+      "name": "ImmutableMutationConfig",
+      "msg": "Price mint can't be changed while executions are pending"
+    },
+    {
+      "code": 6038,
+      "name": "MutationPaused",
+      "msg": "Transmuter or mutation is paused, no new executions can be started"
+    },
+    {
+      "code": 6039,
+      "name": "MissingRole",
+      "msg": "Signer is neither the owner nor holds the role required for this instruction"
+    },
+    {
+      "code": 6040,
+      "name": "NotPendingOwner",
+      "msg": "Signer isn't the transmuter's pending owner"
+    },
+    {
+      "code": 6041,
+      "name": "TransmuterHasMutations",
+      "msg": "Transmuter still has mutations, destroy them first"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidBankSetup",
+      "msg": "Bank a can't be skipped, and the same bank can't be used twice"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidProtocolConfig",
+      "msg": "Too many fee exempt wallets, or execution fee above 100%"
+    },
+    {
+      "code": 6044,
+      "name": "NotUpgradeAuthority",
+      "msg": "Only the program's upgrade authority can init the protocol config"
+    },
+    {
+      "code": 6045,
+      "name": "ExecutionNotPending",
+      "msg": "Only pending executions can be cancelled"
+    },
+    {
+      "code": 6046,
+      "name": "CancellationNotAllowed",
+      "msg": "Executions with a loot or nft roll can't be cancelled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidCancellationPenalty",
+      "msg": "Cancellation penalty can't be more than 100%"
+    },
+    {
+      "code": 6048,
+      "name": "ExecutionStillPending",
+      "msg": "Pending executions have to be completed or cancelled before the receipt can be closed"
+    },
+    {
+      "code": 6049,
+      "name": "MissingVaultGems",
+      "msg": "Gem accounts or bumps missing for a vault that's burned / has its metadata updated"
+    },
+    {
+      "code": 6050,
+      "name": "CancellationWindowClosed",
+      "msg": "Pending executions can only be cancelled before they're due"
+    },
+    {
+      "code": 6051,
+      "name": "ForceCancellationNotAllowed",
+      "msg": "Mutation has to be paused or ended, and the execution overdue, to force cancel it"
+    },
+    {
+      "code": 6052,
+      "name": "OwnerOnlyMutationConfig",
+      "msg": "Only the owner can set revenue splits or have maker tokens minted"
+    },
+    {
+      "code": 6053,
+      "name": "RoleRevoked",
+      "msg": "Role was granted by a previous owner of the transmuter"
+    },
+    {
+      "code": 6054,
+      "name": "ExcessVaultGems",
+      "msg": "Vaults that get burned or have their metadata updated must hold exactly the required amount"
+    },
+    {
+      "code": 6055,
+      "name": "ReceiptLocksVaults",
+      "msg": "Receipt still keeps the taker's vaults locked, it has to be reversed first"
+    }
+  ]
+};
+export const UtransmuterJSON: UtransmuterIDL =
+{
+  "version": "0.1.0",
+  "name": "transmuter_v0",
+  "instructions": [
+    {
+      "name": "initProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "publicKey"
+        },
+        {
+          "name": "transmuterFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "mutationFeeLamports",
+          "type": "u64"
+        },
+        {
+          "name": "feeRecipients",
+          "type": {
+            "vec": {
+              "defined": "RevenueSplit"
+            }
+          }
+        },
+        {
+          "name": "feeExempt",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "executionFeeRecipient",
+          "type": "publicKey"
+        },
+        {
+          "name": "executionFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "initTransmuter",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankC",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bankASetup",
+          "type": {
+            "defined": "BankSetup"
+          }
+        },
+        {
+          "name": "bankBSetup",
+          "type": {
+            "defined": "BankSetup"
+          }
+        },
+        {
+          "name": "bankCSetup",
+          "type": {
+            "defined": "BankSetup"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTransmuter",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newOwner",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "acceptTransmuterOwner",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelTransmuterOwner",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setTransmuterPaused",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTransmuterRole",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transmuterRole",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "member",
+          "type": "publicKey"
+        },
+        {
+          "name": "curator",
+          "type": "bool"
+        },
+        {
+          "name": "mutationManager",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "revokeTransmuterRole",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "transmuterRole",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeTransmuter",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankB",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankC",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "newBankManager",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "addTransmuterBank",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addToBankWhitelist",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "addressToWhitelist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "whitelistType",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromBankWhitelist",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "addressToRemove",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWl",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addRaritiesToBank",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rarityConfigs",
+          "type": {
+            "vec": {
+              "defined": "RarityConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenASource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpB",
+          "type": "u8"
+        },
+        {
+          "name": "bumpC",
+          "type": "u8"
+        },
+        {
+          "name": "config",
+          "type": {
+            "defined": "MutationConfig"
+          }
+        },
+        {
+          "name": "uses",
+          "type": "u64"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "revenueSplits",
+          "type": {
+            "vec": {
+              "defined": "RevenueSplit"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "destroyMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenADest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBDest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCDest",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataTemplate",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "fundMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenASource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenCSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "uses",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "price",
+          "type": {
+            "defined": "PriceConfig"
+          }
+        },
+        {
+          "name": "mutationDurationSec",
+          "type": "u64"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setMutationPaused",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTakerList",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "takerList",
+          "type": {
+            "defined": "TakerList"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMetadataTemplate",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataTemplate",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initNftTemplateList",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "sellerFeeBasisPoints",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "forceCancelMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addNftTemplates",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "adminRole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "templates",
+          "type": {
+            "vec": {
+              "defined": "NftTemplate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initTakerVault",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpCreator",
+          "type": "u8"
+        },
+        {
+          "name": "nonce",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "executeMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataTemplate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenATakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceOwnerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolFeeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMasterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "takerProof",
+          "type": {
+            "option": {
+              "defined": "TakerProof"
+            }
+          }
+        },
+        {
+          "name": "gemBumps",
+          "type": {
+            "vec": {
+              "defined": "GemBoxBumps"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "reverseMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataTemplate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenATakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceOwnerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolFeeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMasterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bankA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaultA",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataTemplate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenATakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceOwnerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "priceEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolFeeRecipient",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTemplateList",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftTakerAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMasterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "takerRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExecutionReceipt",
+      "accounts": [
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "taker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "closer",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateTransmuter",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "legacyMutationCount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "migrateMutation",
+      "accounts": [
+        {
+          "name": "transmuter",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateExecutionReceipt",
+      "accounts": [
+        {
+          "name": "mutation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "executionReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ExecutionReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "mutationCompleteTs",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ExecutionState"
+            }
+          },
+          {
+            "name": "vaultA",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultB",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultC",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "extraVaults",
+            "type": {
+              "array": [
+                {
+                  "option": "publicKey"
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "reversalCount",
+            "type": "u64"
+          },
+          {
+            "name": "commitSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lootOutcome",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "escrowedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "escrowedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataTemplate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mutation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "MutationConfig"
+            }
+          },
+          {
+            "name": "tokenAEscrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "tokenCEscrow",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "extraEscrows",
+            "type": {
+              "array": [
+                {
+                  "option": "publicKey"
+                },
+                2
+              ]
+            }
+          },
+          {
+            "name": "totalUses",
+            "type": "u64"
+          },
+          {
+            "name": "remainingUses",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "MutationState"
+            }
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revenueSplits",
+            "type": {
+              "array": [
+                {
+                  "defined": "RevenueSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "pendingExecutions",
+            "type": "u64"
+          },
+          {
+            "name": "takerList",
+            "type": {
+              "defined": "TakerList"
+            }
+          },
+          {
+            "name": "nftTemplateList",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                19
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftTemplateList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "symbol",
+            "type": {
+              "array": [
+                "u8",
+                10
+              ]
+            }
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "capacity",
+            "type": "u32"
+          },
+          {
+            "name": "uploaded",
+            "type": "u32"
+          },
+          {
+            "name": "minted",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                40
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProtocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "transmuterFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "mutationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "feeRecipients",
+            "type": {
+              "array": [
+                {
+                  "defined": "RevenueSplit"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "feeExempt",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "executionFeeRecipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "executionFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                30
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mutation",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "executions",
+            "type": "u64"
+          },
+          {
+            "name": "lastExecutionTs",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Transmuter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "authoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "bankA",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankB",
+            "type": "publicKey"
+          },
+          {
+            "name": "bankC",
+            "type": "publicKey"
+          },
+          {
+            "name": "extraBanks",
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mutationCount",
+            "type": "u32"
+          },
+          {
+            "name": "unmigratedMutations",
+            "type": "u32"
+          },
+          {
+            "name": "roleEpoch",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransmuterRole",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
+            "type": "publicKey"
+          },
+          {
+            "name": "member",
+            "type": "publicKey"
+          },
+          {
+            "name": "curator",
+            "type": "bool"
+          },
+          {
+            "name": "mutationManager",
+            "type": "bool"
+          },
+          {
+            "name": "roleEpoch",
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                24
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RarityConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GemBoxBumps",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "transmuter",
-            "type": "publicKey"
+            "name": "gemBox",
+            "type": "u8"
           },
           {
-            "name": "mutation",
+            "name": "gemDepositReceipt",
+            "type": "u8"
+          },
+          {
+            "name": "gemRarity",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyTransmuter",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "owner",
             "type": "publicKey"
           },
           {
-            "name": "taker",
+            "name": "authority",
             "type": "publicKey"
           },
           {
-            "name": "mutationCompleteTs",
-            "type": "u64"
+            "name": "authoritySeed",
+            "type": "publicKey"
           },
           {
-            "name": "state",
+            "name": "authorityBumpSeed",
             "type": {
-              "defined": "ExecutionState"
+              "array": [
+                "u8",
+                1
+              ]
             }
           },
           {
-            "name": "vaultA",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "bankA",
+            "type": "publicKey"
           },
           {
-            "name": "vaultB",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "bankB",
+            "type": "publicKey"
           },
           {
-            "name": "vaultC",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "bankC",
+            "type": "publicKey"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                64
               ]
             }
           }
@@ -2142,7 +5960,7 @@ export const UtransmuterJSON: UtransmuterIDL =
       }
     },
     {
-      "name": "Mutation",
+      "name": "LegacyMutation",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2153,7 +5971,7 @@ export const UtransmuterJSON: UtransmuterIDL =
           {
             "name": "config",
             "type": {
-              "defined": "MutationConfig"
+              "defined": "LegacyMutationConfig"
             }
           },
           {
@@ -2208,73 +6026,186 @@ export const UtransmuterJSON: UtransmuterIDL =
       }
     },
     {
-      "name": "Transmuter",
+      "name": "LegacyMutationConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "type": "u16"
+            "name": "takerTokenA",
+            "type": {
+              "defined": "TakerTokenConfig"
+            }
           },
           {
-            "name": "owner",
-            "type": "publicKey"
+            "name": "takerTokenB",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
           },
           {
-            "name": "authority",
-            "type": "publicKey"
+            "name": "takerTokenC",
+            "type": {
+              "option": {
+                "defined": "TakerTokenConfig"
+              }
+            }
           },
           {
-            "name": "authoritySeed",
-            "type": "publicKey"
+            "name": "makerTokenA",
+            "type": {
+              "defined": "LegacyMakerTokenConfig"
+            }
           },
           {
-            "name": "authorityBumpSeed",
+            "name": "makerTokenB",
+            "type": {
+              "option": {
+                "defined": "LegacyMakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "makerTokenC",
+            "type": {
+              "option": {
+                "defined": "LegacyMakerTokenConfig"
+              }
+            }
+          },
+          {
+            "name": "price",
+            "type": {
+              "defined": "LegacyPriceConfig"
+            }
+          },
+          {
+            "name": "mutationDurationSec",
+            "type": "u64"
+          },
+          {
+            "name": "reversible",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                1
+                32
               ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyMakerTokenConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
           },
           {
-            "name": "bankA",
+            "name": "totalFunding",
+            "type": "u64"
+          },
+          {
+            "name": "amountPerUse",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyPriceConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceLamports",
+            "type": "u64"
+          },
+          {
+            "name": "reversalPriceLamports",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyExecutionReceipt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transmuter",
             "type": "publicKey"
           },
           {
-            "name": "bankB",
+            "name": "mutation",
             "type": "publicKey"
           },
           {
-            "name": "bankC",
+            "name": "taker",
             "type": "publicKey"
           },
+          {
+            "name": "mutationCompleteTs",
+            "type": "u64"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "ExecutionState"
+            }
+          },
+          {
+            "name": "vaultA",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultB",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "vaultC",
+            "type": {
+              "option": "publicKey"
+            }
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                64
+                32
               ]
             }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "RarityConfig",
+      "name": "LootTable",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "rarityPoints",
-            "type": "u16"
+            "name": "weights",
+            "type": {
+              "array": [
+                "u16",
+                5
+              ]
+            }
           }
         ]
       }
@@ -2328,26 +6259,94 @@ export const UtransmuterJSON: UtransmuterIDL =
               }
             }
           },
+          {
+            "name": "extraTakerTokens",
+            "type": {
+              "array": [
+                {
+                  "option": {
+                    "defined": "TakerTokenConfig"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "extraMakerTokens",
+            "type": {
+              "array": [
+                {
+                  "option": {
+                    "defined": "MakerTokenConfig"
+                  }
+                },
+                2
+              ]
+            }
+          },
           {
             "name": "price",
             "type": {
-              "defined": "PriceConfig"
+              "defined": "PriceConfig"
+            }
+          },
+          {
+            "name": "mutationDurationSec",
+            "type": "u64"
+          },
+          {
+            "name": "reversible",
+            "type": "bool"
+          },
+          {
+            "name": "reversal",
+            "type": {
+              "defined": "ReversalConfig"
+            }
+          },
+          {
+            "name": "startTs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "endTs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxExecutionsPerTaker",
+            "type": "u64"
+          },
+          {
+            "name": "takerCooldownSec",
+            "type": "u64"
+          },
+          {
+            "name": "lootTable",
+            "type": {
+              "defined": "LootTable"
             }
           },
           {
-            "name": "mutationDurationSec",
-            "type": "u64"
+            "name": "nftReward",
+            "type": {
+              "defined": "NftRewardMode"
+            }
           },
           {
-            "name": "reversible",
-            "type": "bool"
+            "name": "cancellationPenaltyBps",
+            "type": "u16"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                6
               ]
             }
           }
@@ -2398,6 +6397,12 @@ export const UtransmuterJSON: UtransmuterIDL =
           {
             "name": "amountPerUse",
             "type": "u64"
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": "MakerTokenSource"
+            }
           }
         ]
       }
@@ -2414,6 +6419,128 @@ export const UtransmuterJSON: UtransmuterIDL =
           {
             "name": "reversalPriceLamports",
             "type": "i64"
+          },
+          {
+            "name": "priceMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "priceAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reversalPriceAmount",
+            "type": "i64"
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "defined": "PricingMode"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReversalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSec",
+            "type": "u64"
+          },
+          {
+            "name": "endPriceBps",
+            "type": "u64"
+          },
+          {
+            "name": "maxReversals",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevenueSplit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftTemplate",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerList",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "listType",
+            "type": {
+              "defined": "TakerListType"
+            }
+          },
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "lowerBound",
+            "type": "publicKey"
+          },
+          {
+            "name": "upperBound",
+            "type": "publicKey"
           }
         ]
       }
@@ -2463,6 +6590,20 @@ export const UtransmuterJSON: UtransmuterIDL =
         ]
       }
     },
+    {
+      "name": "MakerTokenSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Escrow"
+          },
+          {
+            "name": "Mint"
+          }
+        ]
+      }
+    },
     {
       "name": "VaultAction",
       "type": {
@@ -2476,6 +6617,109 @@ export const UtransmuterJSON: UtransmuterIDL =
           },
           {
             "name": "DoNothing"
+          },
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "UpdateMetadata"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PricingMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "DutchAuction",
+            "fields": [
+              {
+                "name": "startTs",
+                "type": "u64"
+              },
+              {
+                "name": "endTs",
+                "type": "u64"
+              },
+              {
+                "name": "startPriceBps",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "LinearUses",
+            "fields": [
+              {
+                "name": "stepBps",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "ExponentialUses",
+            "fields": [
+              {
+                "name": "growthBps",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "NftRewardMode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Sequential"
+          },
+          {
+            "name": "Random"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TakerListType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Allowlist"
+          },
+          {
+            "name": "Denylist"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BankSetup",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Create"
+          },
+          {
+            "name": "Import"
+          },
+          {
+            "name": "Skip"
           }
         ]
       }
@@ -2499,8 +6743,8 @@ export const UtransmuterJSON: UtransmuterIDL =
     },
     {
       "code": 6003,
-      "name": "CandyMachineMissing",
-      "msg": "Minted mutations require a Candy Machine ID to be present"
+      "name": "MintAuthorityMissing",
+      "msg": "Minted rewards require the transmuter authority to be the mint authority"
     },
     {
       "code": 6004,
@@ -2566,9 +6810,203 @@ export const UtransmuterJSON: UtransmuterIDL =
       "code": 6016,
       "name": "AccountDiscriminatorMismatch",
       "msg": "Account discriminator mismatch"
+    },
+    {
+      "code": 6017,
+      "name": "PriceMintMissing",
+      "msg": "Token prices require a price mint to be set"
+    },
+    {
+      "code": 6018,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account has already been migrated to the latest layout"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRevenueSplits",
+      "msg": "Revenue splits must have non-zero shares adding up to 10000 bps (max 4)"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidPricingMode",
+      "msg": "Dutch auctions need start_ts < end_ts and a start price >= the base price"
+    },
+    {
+      "code": 6021,
+      "name": "ReversalWindowClosed",
+      "msg": "The window for reversing this mutation has closed"
+    },
+    {
+      "code": 6022,
+      "name": "MaxReversalsReached",
+      "msg": "This execution has already been reversed the maximum number of times"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidAvailabilityWindow",
+      "msg": "Mutation start_ts must be before end_ts"
+    },
+    {
+      "code": 6024,
+      "name": "MutationNotYetAvailable",
+      "msg": "This mutation isn't available yet (before start_ts)"
+    },
+    {
+      "code": 6025,
+      "name": "MutationNoLongerAvailable",
+      "msg": "This mutation is no longer available (after end_ts)"
+    },
+    {
+      "code": 6026,
+      "name": "MutationHasPendingExecutions",
+      "msg": "Mutation has executions pending completion"
+    },
+    {
+      "code": 6027,
+      "name": "TakerExecutionLimitReached",
+      "msg": "This taker has reached the max number of executions for this mutation"
+    },
+    {
+      "code": 6028,
+      "name": "TakerCooldownActive",
+      "msg": "This taker has to wait for the cooldown to pass before executing again"
+    },
+    {
+      "code": 6029,
+      "name": "TakerNotAllowed",
+      "msg": "This taker isn't allowed to execute this mutation (invalid allow/denylist proof)"
+    },
+    {
+      "code": 6030,
+      "name": "NoMoreBankSlots",
+      "msg": "Transmuter already has the max number of banks"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidLootTable",
+      "msg": "Loot table weights must only be set on maker slots in use, on a short, non-reversible mutation"
+    },
+    {
+      "code": 6032,
+      "name": "LootNotYetRevealable",
+      "msg": "Loot roll can only be revealed in a later slot than the one it was committed in"
+    },
+    {
+      "code": 6033,
+      "name": "VaultNotFullyBurned",
+      "msg": "Not all gems in the vault were burned, pass accounts for every gem box"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMetadataTemplate",
+      "msg": "Metadata template name / uri are too long (or uri is empty)"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidNftReward",
+      "msg": "Invalid nft reward config or template (too long, reversible, or list already set)"
+    },
+    {
+      "code": 6036,
+      "name": "NftTemplatesExhausted",
+      "msg": "Not enough nft templates left for this execution"
+    },
+    {
+      "code": 6037,
+      "name": "ImmutableMutationConfig",
+      "msg": "Price mint can't be changed while executions are pending"
+    },
+    {
+      "code": 6038,
+      "name": "MutationPaused",
+      "msg": "Transmuter or mutation is paused, no new executions can be started"
+    },
+    {
+      "code": 6039,
+      "name": "MissingRole",
+      "msg": "Signer is neither the owner nor holds the role required for this instruction"
+    },
+    {
+      "code": 6040,
+      "name": "NotPendingOwner",
+      "msg": "Signer isn't the transmuter's pending owner"
+    },
+    {
+      "code": 6041,
+      "name": "TransmuterHasMutations",
+      "msg": "Transmuter still has mutations, destroy them first"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidBankSetup",
+      "msg": "Bank a can't be skipped, and the same bank can't be used twice"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidProtocolConfig",
+      "msg": "Too many fee exempt wallets, or execution fee above 100%"
+    },
+    {
+      "code": 6044,
+      "name": "NotUpgradeAuthority",
+      "msg": "Only the program's upgrade authority can init the protocol config"
+    },
+    {
+      "code": 6045,
+      "name": "ExecutionNotPending",
+      "msg": "Only pending executions can be cancelled"
+    },
+    {
+      "code": 6046,
+      "name": "CancellationNotAllowed",
+      "msg": "Executions with a loot or nft roll can't be cancelled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidCancellationPenalty",
+      "msg": "Cancellation penalty can't be more than 100%"
+    },
+    {
+      "code": 6048,
+      "name": "ExecutionStillPending",
+      "msg": "Pending executions have to be completed or cancelled before the receipt can be closed"
+    },
+    {
+      "code": 6049,
+      "name": "MissingVaultGems",
+      "msg": "Gem accounts or bumps missing for a vault that's burned / has its metadata updated"
+    },
+    {
+      "code": 6050,
+      "name": "CancellationWindowClosed",
+      "msg": "Pending executions can only be cancelled before they're due"
+    },
+    {
+      "code": 6051,
+      "name": "ForceCancellationNotAllowed",
+      "msg": "Mutation has to be paused or ended, and the execution overdue, to force cancel it"
+    },
+    {
+      "code": 6052,
+      "name": "OwnerOnlyMutationConfig",
+      "msg": "Only the owner can set revenue splits or have maker tokens minted"
+    },
+    {
+      "code": 6053,
+      "name": "RoleRevoked",
+      "msg": "Role was granted by a previous owner of the transmuter"
+    },
+    {
+      "code": 6054,
+      "name": "ExcessVaultGems",
+      "msg": "Vaults that get burned or have their metadata updated must hold exactly the required amount"
+    },
+    {
+      "code": 6055,
+      "name": "ReceiptLocksVaults",
+      "msg": "Receipt still keeps the taker's vaults locked, it has to be reversed first"
     }
   ]
-}
-;
+};
 import { generateErrorMap } from '@saberhq/anchor-contrib';
 export const UtransmuterErrors = generateErrorMap(UtransmuterJSON);
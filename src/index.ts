@@ -1,3 +1,5 @@
 export * from "./wrappers";
 export * from "./constants";
 export * from "./sdk";
+export * from "./pda";
+export * from "./taker-list";
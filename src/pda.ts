@@ -2,6 +2,24 @@ import { PublicKey } from "@solana/web3.js";
 import { findVaultPDA } from "@gemworks/gem-farm-ts";
 import { TRANSMUTER_ADDRESSES } from "./constants";
 
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
+export const BPF_UPGRADEABLE_LOADER_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+// a taker's first run of a mutation has no nonce seed (same pdas as before)
+export const nonceSeed = (nonce: number): Buffer => {
+  if (!nonce) {
+    return Buffer.alloc(0);
+  }
+  const seed = Buffer.alloc(4);
+  seed.writeUInt32LE(nonce);
+  return seed;
+};
+
 export const findTransmuterAuthorityPDA = async (
   transmuter: PublicKey
 ): Promise<[PublicKey, number]> => {
@@ -23,10 +41,16 @@ export const findTokenEscrowPDA = async (
 
 export const findVaultCreatorPDA = async (
   mutation: PublicKey,
-  taker: PublicKey
+  taker: PublicKey,
+  nonce = 0
 ): Promise<[PublicKey, number]> => {
   return PublicKey.findProgramAddress(
-    [Buffer.from("creator"), mutation.toBytes(), taker.toBytes()],
+    [
+      Buffer.from("creator"),
+      mutation.toBytes(),
+      taker.toBytes(),
+      nonceSeed(nonce),
+    ],
     TRANSMUTER_ADDRESSES.Transmuter
   );
 };
@@ -34,19 +58,124 @@ export const findVaultCreatorPDA = async (
 export const findTakerVaultPDA = async (
   bank: PublicKey,
   mutation: PublicKey,
-  taker: PublicKey
+  taker: PublicKey,
+  nonce = 0
 ) => {
-  const [creator, creatorBump] = await findVaultCreatorPDA(mutation, taker);
+  const [creator, creatorBump] = await findVaultCreatorPDA(
+    mutation,
+    taker,
+    nonce
+  );
   const [vault, vaultBump] = await findVaultPDA(bank, creator);
   return { creator, creatorBump, vault, vaultBump };
 };
 
 export const findExecutionReceiptPDA = async (
+  mutation: PublicKey,
+  taker: PublicKey,
+  nonce = 0
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [
+      Buffer.from("receipt"),
+      mutation.toBytes(),
+      taker.toBytes(),
+      nonceSeed(nonce),
+    ],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+export const findTakerRecordPDA = async (
   mutation: PublicKey,
   taker: PublicKey
 ): Promise<[PublicKey, number]> => {
   return PublicKey.findProgramAddress(
-    [Buffer.from("receipt"), mutation.toBytes(), taker.toBytes()],
+    [Buffer.from("taker_record"), mutation.toBytes(), taker.toBytes()],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+export const findProtocolConfigPDA = async (): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("protocol_config")],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+export const findTransmuterRolePDA = async (
+  transmuter: PublicKey,
+  member: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("role"), transmuter.toBytes(), member.toBytes()],
     TRANSMUTER_ADDRESSES.Transmuter
   );
 };
+
+export const findMetadataTemplatePDA = async (
+  mutation: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("metadata_template"), mutation.toBytes()],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+export const findProgramDataPDA = async (): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [TRANSMUTER_ADDRESSES.Transmuter.toBytes()],
+    BPF_UPGRADEABLE_LOADER_ID
+  );
+};
+
+export const findMetadataPDA = async (
+  mint: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBytes(),
+      mint.toBytes(),
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+};
+
+export const findPaymentEscrowPDA = async (
+  executionReceipt: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("payment_escrow"), executionReceipt.toBytes()],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+/**
+ * mintIndex = the template list's minted count when the nft is handed out
+ */
+export const findNftRewardMintPDA = async (
+  nftTemplateList: PublicKey,
+  mintIndex: number
+): Promise<[PublicKey, number]> => {
+  const index = Buffer.alloc(4);
+  index.writeUInt32LE(mintIndex);
+  return PublicKey.findProgramAddress(
+    [Buffer.from("nft_reward"), nftTemplateList.toBytes(), index],
+    TRANSMUTER_ADDRESSES.Transmuter
+  );
+};
+
+export const findMasterEditionPDA = async (
+  mint: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBytes(),
+      mint.toBytes(),
+      Buffer.from("edition"),
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+};
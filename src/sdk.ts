@@ -8,13 +8,14 @@ import { Programs, TRANSMUTER_ADDRESSES, TRANSMUTER_IDLS } from "./constants";
 import { newProgramMap } from "@saberhq/anchor-contrib";
 import { MutationWrapper, TransmuterWrapper } from "./wrappers";
 import {
+  AccountMeta,
   Keypair,
   PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
   TransactionInstruction,
 } from "@solana/web3.js";
-import { BN } from "@project-serum/anchor";
+import { BN, BorshAccountsCoder, utils } from "@project-serum/anchor";
 import { GEM_BANK_PROG_ID, toBN } from "@gemworks/gem-farm-ts";
 import {
   createMint,
@@ -27,17 +28,13 @@ import {
 import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
 import {
   findExecutionReceiptPDA,
+  findProgramDataPDA,
+  findProtocolConfigPDA,
   findTokenEscrowPDA,
   findTransmuterAuthorityPDA,
+  findTransmuterRolePDA,
 } from "./pda";
 
-export const feeAccount = new PublicKey(
-  "2U9sG2BRF8TbUjor1Dms8rRRxVqAjJSktZYCwhXFNYCC"
-);
-export const feeAccount2 = new PublicKey(
-  "BkRfbyZWsDwQWvJTibjJ1wuvBuFsw8HvfgHEEDCXHDRQ"
-);
-
 export interface TakerTokenConfig {
   gemBank: PublicKey;
   requiredAmount: BN;
@@ -49,6 +46,7 @@ export interface MakerTokenConfig {
   mint: PublicKey;
   totalFunding: BN;
   amountPerUse: BN;
+  source?: any; //defaults to MakerTokenSource.Escrow
 }
 
 export const RequiredUnits = {
@@ -60,6 +58,13 @@ export const VaultAction = {
   ChangeOwner: { changeOwner: {} },
   Lock: { lock: {} },
   DoNothing: { doNothing: {} },
+  Burn: { burn: {} },
+  UpdateMetadata: { updateMetadata: {} },
+};
+
+export const MakerTokenSource = {
+  Escrow: { escrow: {} },
+  Mint: { mint: {} },
 };
 
 export const MutationState = {
@@ -73,11 +78,97 @@ export const ExecutionState = {
   Complete: { complete: {} },
 };
 
+export const PricingMode = {
+  Fixed: { fixed: {} },
+  DutchAuction: (startTs: BN, endTs: BN, startPriceBps: BN) => ({
+    dutchAuction: { startTs, endTs, startPriceBps },
+  }),
+  LinearUses: (stepBps: BN) => ({ linearUses: { stepBps } }),
+  ExponentialUses: (growthBps: BN) => ({ exponentialUses: { growthBps } }),
+};
+
+export const NftRewardMode = {
+  None: { none: {} },
+  Sequential: { sequential: {} },
+  Random: { random: {} },
+};
+
+export const TakerListType = {
+  None: { none: {} },
+  Allowlist: { allowlist: {} },
+  Denylist: { denylist: {} },
+};
+
+export const BankSetup = {
+  Create: { create: {} },
+  Import: { import: {} },
+  Skip: { skip: {} },
+};
+
+// pass as uses to create a mutation that never runs out (all maker tokens have to be minted)
+export const UNCAPPED_USES = new BN("18446744073709551615");
+
+export const BPS_DENOMINATOR = 10_000;
+
+export const LOOT_TABLE_SIZE = 5;
+
+export const FORCE_CANCEL_GRACE_SEC = 24 * 60 * 60;
+
 export interface PriceConfig {
   priceLamports: BN;
   reversalPriceLamports: BN;
+  priceMint?: PublicKey | null;
+  priceAmount?: BN;
+  reversalPriceAmount?: BN;
+  pricingMode?: any; //defaults to PricingMode.Fixed
+}
+
+export interface ReversalConfig {
+  windowSec: BN;
+  endPriceBps: BN;
+  maxReversals: BN;
 }
 
+export interface LootTable {
+  //one per maker slot (a, b, c, then the extra ones), all 0 = disabled
+  weights: number[];
+}
+
+export interface RevenueSplit {
+  recipient: PublicKey;
+  shareBps: number;
+}
+
+export interface TakerList {
+  listType: any;
+  root: number[];
+}
+
+export interface TakerProof {
+  proof: number[][];
+  lowerBound: PublicKey;
+  upperBound: PublicKey;
+}
+
+export interface GemBoxBumps {
+  gemBox: number;
+  gemDepositReceipt: number;
+  gemRarity: number;
+}
+
+export interface NftTemplate {
+  name: string;
+  uri: string;
+}
+
+export interface BankSetupChoice {
+  setup: any;
+  bank?: PublicKey; //only for BankSetup.Import
+}
+
+/**
+ * everything added after the first release is optional, and switched off if left out
+ */
 export interface MutationConfig {
   takerTokenA: TakerTokenConfig;
   takerTokenB: TakerTokenConfig | null;
@@ -87,13 +178,86 @@ export interface MutationConfig {
   makerTokenB: MakerTokenConfig | null;
   makerTokenC: MakerTokenConfig | null;
 
+  //up to 3, deposited into transmuter.extraBanks
+  extraTakerTokens?: (TakerTokenConfig | null)[];
+  extraMakerTokens?: (MakerTokenConfig | null)[]; //up to 2
+
   price: PriceConfig;
 
   mutationDurationSec: BN;
 
   reversible: boolean;
+  reversal?: ReversalConfig;
+
+  startTs?: BN | null;
+  endTs?: BN | null;
+
+  maxExecutionsPerTaker?: BN;
+  takerCooldownSec?: BN;
+
+  lootTable?: LootTable;
+
+  nftReward?: any;
+
+  cancellationPenaltyBps?: number;
 }
 
+const padSlots = <T>(slots: (T | null)[] | undefined, len: number) => {
+  const padded = [...(slots ?? [])];
+  while (padded.length < len) {
+    padded.push(null);
+  }
+  return padded;
+};
+
+/**
+ * fills in the defaults for any optional fields, to get the config as stored on chain
+ */
+export const completeMutationConfig = (config: MutationConfig) => {
+  const completeMakerToken = (makerToken: MakerTokenConfig | null) =>
+    makerToken
+      ? { ...makerToken, source: makerToken.source ?? MakerTokenSource.Escrow }
+      : null;
+
+  return {
+    takerTokenA: config.takerTokenA,
+    takerTokenB: config.takerTokenB,
+    takerTokenC: config.takerTokenC,
+    makerTokenA: completeMakerToken(config.makerTokenA),
+    makerTokenB: completeMakerToken(config.makerTokenB),
+    makerTokenC: completeMakerToken(config.makerTokenC),
+    extraTakerTokens: padSlots(config.extraTakerTokens, 3),
+    extraMakerTokens: padSlots(config.extraMakerTokens, 2).map(
+      completeMakerToken
+    ),
+    price: {
+      priceLamports: config.price.priceLamports,
+      reversalPriceLamports: config.price.reversalPriceLamports,
+      priceMint: config.price.priceMint ?? null,
+      priceAmount: config.price.priceAmount ?? toBN(0),
+      reversalPriceAmount: config.price.reversalPriceAmount ?? toBN(0),
+      pricingMode: config.price.pricingMode ?? PricingMode.Fixed,
+    },
+    mutationDurationSec: config.mutationDurationSec,
+    reversible: config.reversible,
+    reversal: config.reversal ?? {
+      windowSec: toBN(0),
+      endPriceBps: toBN(BPS_DENOMINATOR),
+      maxReversals: toBN(0),
+    },
+    startTs: config.startTs ?? null,
+    endTs: config.endTs ?? null,
+    maxExecutionsPerTaker: config.maxExecutionsPerTaker ?? toBN(0),
+    takerCooldownSec: config.takerCooldownSec ?? toBN(0),
+    lootTable: config.lootTable ?? {
+      weights: new Array(LOOT_TABLE_SIZE).fill(0),
+    },
+    nftReward: config.nftReward ?? NftRewardMode.None,
+    cancellationPenaltyBps: config.cancellationPenaltyBps ?? 0,
+    reserved: new Array(6).fill(0),
+  };
+};
+
 export class TransmuterSDK {
   constructor(
     readonly provider: AugmentedProvider,
@@ -102,11 +266,23 @@ export class TransmuterSDK {
 
   // --------------------------------------- fetchers
 
-  async fetchReceipt(mutation: PublicKey, taker: PublicKey) {
-    const [receiptAddr] = await findExecutionReceiptPDA(mutation, taker);
+  async fetchReceipt(mutation: PublicKey, taker: PublicKey, nonce = 0) {
+    const [receiptAddr] = await findExecutionReceiptPDA(mutation, taker, nonce);
     return this.programs.Transmuter.account.executionReceipt.fetch(receiptAddr);
   }
 
+  async fetchProtocolConfig() {
+    const [protocolConfig] = await findProtocolConfigPDA();
+    return this.programs.Transmuter.account.protocolConfig.fetch(
+      protocolConfig
+    );
+  }
+
+  async fetchRole(transmuter: PublicKey, member: PublicKey) {
+    const [role] = await findTransmuterRolePDA(transmuter, member);
+    return this.programs.Transmuter.account.transmuterRole.fetch(role);
+  }
+
   // --------------------------------------- finders
 
   async findAllReceipts(
@@ -147,93 +323,253 @@ export class TransmuterSDK {
     return this.programs.Transmuter.account.executionReceipt.all(filter);
   }
 
-  // --------------------------------------- initializers
+  /**
+   * counted from the raw accounts, so legacy mutations (which don't decode anymore) are included
+   */
+  async countMutations(transmuter: PublicKey) {
+    const mutations = await this.provider.connection.getProgramAccounts(
+      this.programs.Transmuter.programId,
+      {
+        dataSlice: { offset: 0, length: 0 },
+        filters: [
+          {
+            memcmp: {
+              offset: 0,
+              bytes: utils.bytes.bs58.encode(
+                BorshAccountsCoder.accountDiscriminator("Mutation")
+              ),
+            },
+          },
+          { memcmp: { offset: 8, bytes: transmuter.toBase58() } },
+        ],
+      }
+    );
+    return mutations.length;
+  }
 
-  async initTransmuter(payer?: PublicKey) {
-    const transmuter = Keypair.generate();
-    const bankA = Keypair.generate();
-    const bankB = Keypair.generate();
-    const bankC = Keypair.generate();
+  // --------------------------------------- protocol config
 
-    const [authority, bump] = await findTransmuterAuthorityPDA(
-      transmuter.publicKey
-    );
+  /**
+   * can only be called once, by the program's upgrade authority
+   */
+  async initProtocolConfig() {
+    const [protocolConfig] = await findProtocolConfigPDA();
+    const [programData] = await findProgramDataPDA();
 
-    const ix = this.programs.Transmuter.instruction.initTransmuter(bump, {
+    const ix = this.programs.Transmuter.instruction.initProtocolConfig({
       accounts: {
-        transmuter: transmuter.publicKey,
-        owner: this.provider.wallet.publicKey,
-        authority,
-        bankA: bankA.publicKey,
-        bankB: bankB.publicKey,
-        bankC: bankC.publicKey,
-        gemBank: GEM_BANK_PROG_ID,
-        payer: payer ?? this.provider.wallet.publicKey,
-        feeAcc: feeAccount,
-        feeAcc2: feeAccount2,
+        protocolConfig,
+        admin: this.provider.wallet.publicKey,
+        program: TRANSMUTER_ADDRESSES.Transmuter,
+        programData,
         systemProgram: SystemProgram.programId,
       },
     });
 
+    return {
+      protocolConfig,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * replaces all fee settings at once
+   */
+  async updateProtocolConfig({
+    newAdmin,
+    transmuterFeeLamports = toBN(0),
+    mutationFeeLamports = toBN(0),
+    feeRecipients = [],
+    feeExempt = [],
+    executionFeeRecipient = PublicKey.default,
+    executionFeeBps = 0,
+    admin,
+  }: {
+    newAdmin?: PublicKey;
+    transmuterFeeLamports?: BN;
+    mutationFeeLamports?: BN;
+    feeRecipients?: RevenueSplit[];
+    feeExempt?: PublicKey[];
+    executionFeeRecipient?: PublicKey;
+    executionFeeBps?: number;
+    admin?: PublicKey;
+  }) {
+    const [protocolConfig] = await findProtocolConfigPDA();
+    admin = admin ?? this.provider.wallet.publicKey;
+
+    const ix = this.programs.Transmuter.instruction.updateProtocolConfig(
+      newAdmin ?? admin,
+      transmuterFeeLamports,
+      mutationFeeLamports,
+      feeRecipients,
+      feeExempt,
+      executionFeeRecipient,
+      executionFeeBps,
+      {
+        accounts: {
+          protocolConfig,
+          admin,
+        },
+      }
+    );
+
+    return {
+      protocolConfig,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  // --------------------------------------- initializers
+
+  async initTransmuter(
+    payer?: PublicKey,
+    {
+      bankA = { setup: BankSetup.Create },
+      bankB = { setup: BankSetup.Create },
+      bankC = { setup: BankSetup.Create },
+      transmuter = Keypair.generate(),
+    }: {
+      bankA?: BankSetupChoice;
+      bankB?: BankSetupChoice;
+      bankC?: BankSetupChoice;
+      transmuter?: Keypair; //known upfront when importing banks, to hand them to its authority first
+    } = {}
+  ) {
+    const signers = [transmuter];
+
+    // created banks sign, imported ones are passed as is, skipped ones are fake
+    const prepBank = (choice: BankSetupChoice) => {
+      if (choice.setup.create) {
+        const bank = Keypair.generate();
+        signers.push(bank);
+        return bank.publicKey;
+      }
+      if (choice.setup.import) {
+        return choice.bank;
+      }
+      return PublicKey.default;
+    };
+    const bankAKey = prepBank(bankA);
+    const bankBKey = prepBank(bankB);
+    const bankCKey = prepBank(bankC);
+
+    const [authority, bump] = await findTransmuterAuthorityPDA(
+      transmuter.publicKey
+    );
+    const [protocolConfig] = await findProtocolConfigPDA();
+
+    const ix = this.programs.Transmuter.instruction.initTransmuter(
+      bump,
+      bankA.setup,
+      bankB.setup,
+      bankC.setup,
+      {
+        accounts: {
+          transmuter: transmuter.publicKey,
+          owner: this.provider.wallet.publicKey,
+          authority,
+          bankA: bankAKey,
+          bankB: bankBKey,
+          bankC: bankCKey,
+          gemBank: GEM_BANK_PROG_ID,
+          payer: payer ?? this.provider.wallet.publicKey,
+          protocolConfig,
+          systemProgram: SystemProgram.programId,
+        },
+        remainingAccounts: await this.prepProtocolFeeRecipients(),
+      }
+    );
+
     return {
       transmuterWrapper: new TransmuterWrapper(
         this,
         transmuter.publicKey,
-        bankA.publicKey,
-        bankB.publicKey,
-        bankC.publicKey
+        bankAKey,
+        bankBKey,
+        bankCKey
       ),
       authority,
-      tx: new TransactionEnvelope(
-        this.provider,
-        [ix],
-        [transmuter, bankA, bankB, bankC]
-      ),
+      tx: new TransactionEnvelope(this.provider, [ix], signers),
     };
   }
 
+  /**
+   * escrows are funded from the admin's ATAs, admin defaults to the wallet (ie the transmuter owner)
+   */
   async initMutation(
     config: MutationConfig,
     transmuter: PublicKey,
     uses: BN,
     payer?: PublicKey,
-    name: string = "mutation"
+    name: string = "mutation",
+    {
+      admin,
+      revenueSplits = [],
+    }: {
+      admin?: PublicKey;
+      revenueSplits?: RevenueSplit[];
+    } = {}
   ) {
     const mutation = Keypair.generate();
+    admin = admin ?? this.provider.wallet.publicKey;
 
     const tokenAMint =
       config.makerTokenA.mint ?? (await createMint(this.provider));
     const [tokenAEscrow, tokenAEscrowBump, tokenASource] =
-      await this.prepTokenAccounts(mutation.publicKey, tokenAMint);
+      await this.prepTokenAccounts(mutation.publicKey, tokenAMint, admin);
 
     const tokenBMint =
       config.makerTokenB && config.makerTokenB.mint
         ? config.makerTokenB.mint
         : await createMint(this.provider);
     const [tokenBEscrow, tokenBEscrowBump, tokenBSource] =
-      await this.prepTokenAccounts(mutation.publicKey, tokenBMint);
+      await this.prepTokenAccounts(mutation.publicKey, tokenBMint, admin);
 
     const tokenCMint =
       config.makerTokenC && config.makerTokenC.mint
         ? config.makerTokenC.mint
         : await createMint(this.provider);
     const [tokenCEscrow, tokenCEscrowBump, tokenCSource] =
-      await this.prepTokenAccounts(mutation.publicKey, tokenCMint);
+      await this.prepTokenAccounts(mutation.publicKey, tokenCMint, admin);
+
+    // extra escrows go first, then the protocol fee recipients
+    const remainingAccounts: AccountMeta[] = [];
+    for (const makerToken of config.extraMakerTokens ?? []) {
+      if (!makerToken) {
+        continue;
+      }
+      const [escrow, , source] = await this.prepTokenAccounts(
+        mutation.publicKey,
+        makerToken.mint,
+        admin
+      );
+      remainingAccounts.push(
+        { pubkey: escrow, isWritable: true, isSigner: false },
+        { pubkey: source, isWritable: true, isSigner: false },
+        { pubkey: makerToken.mint, isWritable: false, isSigner: false }
+      );
+    }
+
+    remainingAccounts.push(...(await this.prepProtocolFeeRecipients()));
 
     const [authority, bump] = await findTransmuterAuthorityPDA(transmuter);
+    const [adminRole] = await findTransmuterRolePDA(transmuter, admin);
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const ix = this.programs.Transmuter.instruction.initMutation(
       bump,
       tokenBEscrowBump,
       tokenCEscrowBump,
-      config as any,
+      completeMutationConfig(config) as any,
       uses,
       name,
+      revenueSplits,
       {
         accounts: {
           transmuter,
           mutation: mutation.publicKey,
-          owner: this.provider.wallet.publicKey,
+          admin,
+          adminRole,
           authority,
           tokenAEscrow,
           tokenASource,
@@ -245,12 +581,12 @@ export class TransmuterSDK {
           tokenCSource,
           tokenCMint,
           payer: payer ?? this.provider.wallet.publicKey,
-          feeAcc: feeAccount,
-          feeAcc2: feeAccount2,
+          protocolConfig,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
         },
+        remainingAccounts,
       }
     );
 
@@ -265,6 +601,72 @@ export class TransmuterSDK {
     };
   }
 
+  // --------------------------------------- migrations
+
+  /**
+   * owner has to sign, declaring the number of legacy mutations (counted here)
+   */
+  async migrateTransmuter(
+    transmuter: PublicKey,
+    owner: PublicKey = this.provider.wallet.publicKey,
+    payer?: PublicKey
+  ) {
+    const legacyMutationCount = await this.countMutations(transmuter);
+    const ix = this.programs.Transmuter.instruction.migrateTransmuter(
+      legacyMutationCount,
+      {
+        accounts: {
+          transmuter,
+          owner,
+          payer: payer ?? this.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  /**
+   * transmuter has to be migrated first
+   */
+  async migrateMutation(
+    transmuter: PublicKey,
+    mutation: PublicKey,
+    payer?: PublicKey
+  ) {
+    const ix = this.programs.Transmuter.instruction.migrateMutation({
+      accounts: {
+        transmuter,
+        mutation,
+        payer: payer ?? this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  /**
+   * mutation has to be migrated first
+   */
+  async migrateExecutionReceipt(
+    mutation: PublicKey,
+    executionReceipt: PublicKey,
+    payer?: PublicKey
+  ) {
+    const ix = this.programs.Transmuter.instruction.migrateExecutionReceipt({
+      accounts: {
+        mutation,
+        executionReceipt,
+        payer: payer ?? this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
   // --------------------------------------- helpers
 
   async prepTokenAccounts(
@@ -284,6 +686,16 @@ export class TransmuterSDK {
     return [tokenEscrow, tokenEscrowBump, tokenAcc];
   }
 
+  /**
+   * wallets of the protocol fee recipients, in protocol config order
+   */
+  async prepProtocolFeeRecipients(): Promise<AccountMeta[]> {
+    const protocolConfig = await this.fetchProtocolConfig();
+    return (protocolConfig.feeRecipients as RevenueSplit[])
+      .filter((r) => r.shareBps > 0)
+      .map((r) => ({ pubkey: r.recipient, isWritable: true, isSigner: false }));
+  }
+
   async createMintAndATA(initialFunding: u64, owner?: Keypair) {
     //create mint
     const mint = await createMint(
@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "js-sha3";
+
+// domain separation, has to match the program's
+const LEAF_PREFIX = Buffer.from([0]);
+const NODE_PREFIX = Buffer.from([1]);
+
+export const DENYLIST_MIN_BOUND = PublicKey.default;
+export const DENYLIST_MAX_BOUND = new PublicKey(Buffer.alloc(32, 255));
+
+const keccak = (...data: Buffer[]): Buffer =>
+  Buffer.from(keccak_256.arrayBuffer(Buffer.concat(data)));
+
+export const hashLeaf = (...data: Buffer[]): Buffer =>
+  keccak(LEAF_PREFIX, ...data);
+
+// sorted pair hashing, so proofs don't need to record which side a node is on
+export const hashNode = (a: Buffer, b: Buffer): Buffer =>
+  Buffer.compare(a, b) <= 0
+    ? keccak(NODE_PREFIX, a, b)
+    : keccak(NODE_PREFIX, b, a);
+
+/**
+ * Merkle tree behind a mutation's taker list.
+ * Allowlist leaves are hash(taker), denylist leaves are hash(lower, upper)
+ * for every gap between two consecutive blocked wallets - a taker proves
+ * they're not blocked by proving the gap they sit in
+ */
+export class TakerListTree {
+  readonly layers: Buffer[][];
+
+  constructor(readonly leaves: Buffer[], readonly blocked?: PublicKey[]) {
+    if (!leaves.length) {
+      throw new Error("taker list needs at least one leaf");
+    }
+    this.layers = [leaves];
+    while (this.layers[this.layers.length - 1].length > 1) {
+      const layer = this.layers[this.layers.length - 1];
+      const next = [];
+      for (let i = 0; i < layer.length; i += 2) {
+        // odd node out is carried up as is
+        next.push(
+          i + 1 < layer.length ? hashNode(layer[i], layer[i + 1]) : layer[i]
+        );
+      }
+      this.layers.push(next);
+    }
+  }
+
+  static allowlist(takers: PublicKey[]): TakerListTree {
+    return new TakerListTree(takers.map((t) => hashLeaf(t.toBuffer())));
+  }
+
+  static denylist(blocked: PublicKey[]): TakerListTree {
+    const sorted = [...blocked].sort((a, b) =>
+      Buffer.compare(a.toBuffer(), b.toBuffer())
+    );
+    const bounds = [DENYLIST_MIN_BOUND, ...sorted, DENYLIST_MAX_BOUND];
+    const leaves = [];
+    for (let i = 0; i + 1 < bounds.length; i++) {
+      leaves.push(hashLeaf(bounds[i].toBuffer(), bounds[i + 1].toBuffer()));
+    }
+    return new TakerListTree(leaves, sorted);
+  }
+
+  get root(): number[] {
+    return [...this.layers[this.layers.length - 1][0]];
+  }
+
+  proofFor(leaf: Buffer): number[][] {
+    let index = this.leaves.findIndex((l) => l.equals(leaf));
+    if (index < 0) {
+      throw new Error("leaf not in taker list");
+    }
+    const proof = [];
+    for (const layer of this.layers.slice(0, -1)) {
+      const sibling = index % 2 ? index - 1 : index + 1;
+      if (sibling < layer.length) {
+        proof.push([...layer[sibling]]);
+      }
+      index = Math.floor(index / 2);
+    }
+    return proof;
+  }
+
+  /**
+   * the TakerProof to pass to execute for this taker
+   */
+  takerProof(taker: PublicKey) {
+    if (!this.blocked) {
+      return {
+        proof: this.proofFor(hashLeaf(taker.toBuffer())),
+        lowerBound: PublicKey.default,
+        upperBound: PublicKey.default,
+      };
+    }
+
+    const bounds = [DENYLIST_MIN_BOUND, ...this.blocked, DENYLIST_MAX_BOUND];
+    const upper = bounds.findIndex(
+      (b) => Buffer.compare(taker.toBuffer(), b.toBuffer()) < 0
+    );
+    const lowerBound = bounds[upper - 1];
+    const upperBound = bounds[upper];
+    return {
+      proof: this.proofFor(
+        hashLeaf(lowerBound.toBuffer(), upperBound.toBuffer())
+      ),
+      lowerBound,
+      upperBound,
+    };
+  }
+}
@@ -1,27 +1,62 @@
-import { TransmuterSDK } from "../sdk";
 import {
+  GemBoxBumps,
+  NftTemplate,
+  PriceConfig,
+  TakerList,
+  TakerProof,
+  TransmuterSDK,
+} from "../sdk";
+import {
+  AccountMeta,
   Keypair,
   PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
 } from "@solana/web3.js";
 import {
   AugmentedProvider,
   TransactionEnvelope,
 } from "@saberhq/solana-contrib";
 import { MutationData, TransmuterProgram } from "../constants";
-import { GEM_BANK_PROG_ID } from "@gemworks/gem-farm-ts";
+import {
+  findGdrPDA,
+  findGemBoxPDA,
+  findRarityPDA,
+  findVaultAuthorityPDA,
+  GEM_BANK_PROG_ID,
+} from "@gemworks/gem-farm-ts";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { createMint } from "@saberhq/token-utils";
+import { createMint, getATAAddress } from "@saberhq/token-utils";
+import { BN, BorshAccountsCoder, utils } from "@project-serum/anchor";
 import {
   findExecutionReceiptPDA,
+  findMasterEditionPDA,
+  findMetadataPDA,
+  findMetadataTemplatePDA,
+  findNftRewardMintPDA,
+  findPaymentEscrowPDA,
+  findProtocolConfigPDA,
+  findTakerRecordPDA,
   findTakerVaultPDA,
   findTransmuterAuthorityPDA,
+  findTransmuterRolePDA,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "../pda";
 
+// size of an nft template list header (incl. discriminator) & of each template after it
+export const NFT_TEMPLATE_LIST_HEADER_LEN = 8 + 96;
+export const NFT_TEMPLATE_LEN = 32 + 200;
+
+const meta = (pubkey: PublicKey, isWritable: boolean): AccountMeta => ({
+  pubkey,
+  isWritable,
+  isSigner: false,
+});
+
 export class MutationWrapper {
   private _data?: MutationData;
 
@@ -51,174 +86,476 @@ export class MutationWrapper {
 
   // --------------------------------------- ixs
 
-  async execute(taker: PublicKey, newMaxCompute?: number, owner?: PublicKey) {
-    return this._executeOrReverse(taker, false, newMaxCompute, owner);
+  async execute(
+    taker: PublicKey,
+    newMaxCompute?: number,
+    {
+      nonce = 0,
+      takerProof = null,
+    }: { nonce?: number; takerProof?: TakerProof | null } = {}
+  ) {
+    const {
+      accounts,
+      takerSlots,
+      makerSlots,
+      splits,
+      executionReceipt,
+      config,
+    } = await this._prepExecution(taker, nonce);
+
+    // gems acted on by the vault actions are only needed on the call that completes the execution
+    let vaultGems: AccountMeta[] = [];
+    let gemBumps: GemBoxBumps[] = [];
+    const receipt = await this.program.account.executionReceipt.fetchNullable(
+      executionReceipt
+    );
+    const state = receipt ? (receipt.state as any) : { notStarted: {} };
+    if (state.pending || (state.notStarted && !this._needsPendingPhase())) {
+      ({ vaultGems, gemBumps } = await this._prepVaultGems(
+        taker,
+        nonce,
+        config
+      ));
+    }
+
+    const ix = this.program.instruction.executeMutation(
+      takerProof,
+      gemBumps,
+      {
+        accounts,
+        remainingAccounts: [
+          ...takerSlots,
+          ...makerSlots,
+          ...vaultGems,
+          ...splits,
+        ],
+      }
+    );
+
+    const instructions = [ix];
+
+    if (newMaxCompute) {
+      const extraComputeIx = this.sdk.createExtraComputeIx(newMaxCompute);
+      instructions.unshift(extraComputeIx);
+    }
+
+    return {
+      authority: accounts.authority,
+      executionReceipt,
+      tx: new TransactionEnvelope(this.provider, instructions),
+    };
   }
 
-  async reverse(taker: PublicKey, owner?: PublicKey) {
-    return this._executeOrReverse(taker, true, undefined, owner);
+  async reverse(taker: PublicKey, nonce = 0) {
+    const { accounts, takerSlots, makerSlots, splits, executionReceipt } =
+      await this._prepExecution(taker, nonce);
+
+    const ix = this.program.instruction.reverseMutation({
+      accounts,
+      remainingAccounts: [...takerSlots, ...makerSlots, ...splits],
+    });
+
+    return {
+      authority: accounts.authority,
+      executionReceipt,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
   }
 
-  async _executeOrReverse(
-    taker: PublicKey,
-    reverse = false,
-    newMaxCompute?: number,
-    owner?: PublicKey
-  ) {
+  async cancel(taker: PublicKey, nonce = 0) {
+    const { accounts, takerSlots, makerSlots, splits, executionReceipt } =
+      await this._prepExecution(taker, nonce);
+
+    const ix = this.program.instruction.cancelMutation({
+      accounts,
+      remainingAccounts: [...takerSlots, ...makerSlots, ...splits],
+    });
+
+    return {
+      authority: accounts.authority,
+      executionReceipt,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * only once the mutation is paused / ended and the execution has been overdue for a day
+   */
+  async forceCancel(taker: PublicKey, nonce = 0, admin?: PublicKey) {
+    const { accounts, takerSlots, executionReceipt } =
+      await this._prepExecution(taker, nonce);
+    admin = admin ?? this.provider.wallet.publicKey;
+    const [adminRole] = await findTransmuterRolePDA(this.transmuter, admin);
+
+    const ix = this.program.instruction.forceCancelMutation({
+      accounts: {
+        transmuter: this.transmuter,
+        mutation: this.key,
+        admin,
+        adminRole,
+        authority: accounts.authority,
+        executionReceipt,
+        taker,
+        takerRecord: accounts.takerRecord,
+        bankA: accounts.bankA,
+        vaultA: accounts.vaultA,
+        gemBank: GEM_BANK_PROG_ID,
+        priceMint: accounts.priceMint,
+        priceEscrow: accounts.priceEscrow,
+        priceTakerAta: accounts.priceTakerAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      },
+      remainingAccounts: takerSlots,
+    });
+
+    return {
+      authority: accounts.authority,
+      executionReceipt,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * accounts shared by execute, reverse & cancel
+   */
+  async _prepExecution(taker: PublicKey, nonce = 0) {
     await this.reloadData();
-    let config = this._data.config as any;
+    const config = this._data.config as any;
+    const transmuterData = await this.program.account.transmuter.fetch(
+      this.transmuter
+    );
+    const owner = transmuterData.owner;
 
-    // ----------------- prep banks & vaults
-    // if a bank doesn't exist, we create a fake bank. Cheaper (compute) than optional accs
+    // ----------------- prep slot a (the others go into remaining accounts)
 
     const bankA = config.takerTokenA.gemBank;
-    const { vault: vaultA } = await findTakerVaultPDA(bankA, this.key, taker);
-    const bankB = config.takerTokenB
-      ? config.takerTokenB.gemBank
-      : Keypair.generate().publicKey;
-    const { vault: vaultB } = await findTakerVaultPDA(bankB, this.key, taker);
-    const bankC = config.takerTokenC
-      ? config.takerTokenC.gemBank
-      : Keypair.generate().publicKey;
-    const { vault: vaultC } = await findTakerVaultPDA(bankC, this.key, taker);
-
-    // ----------------- prep escrows
+    const { vault: vaultA } = await findTakerVaultPDA(
+      bankA,
+      this.key,
+      taker,
+      nonce
+    );
 
     const tokenAMint = config.makerTokenA.mint;
     const [tokenAEscrow, tokenAEscrowBump, tokenATakerAta] =
       await this.sdk.prepTokenAccounts(this.key, tokenAMint, taker);
 
-    const tokenBMint = config.makerTokenB
-      ? config.makerTokenB.mint
-      : await createMint(this.provider);
-    const [tokenBEscrow, tokenBEscrowBump, tokenBTakerAta] =
-      await this.sdk.prepTokenAccounts(this.key, tokenBMint, taker);
-
-    const tokenCMint = config.makerTokenC
-      ? config.makerTokenC.mint
-      : await createMint(this.provider);
-    const [tokenCEscrow, tokenCEscrowBump, tokenCTakerAta] =
-      await this.sdk.prepTokenAccounts(this.key, tokenCMint, taker);
+    // ----------------- prep payment
 
-    // ----------------- prep ix
-
-    const [authority] = await findTransmuterAuthorityPDA(this.transmuter);
-
-    const [executionReceipt, receiptBump] = await findExecutionReceiptPDA(
+    const [executionReceipt] = await findExecutionReceiptPDA(
       this.key,
-      taker
+      taker,
+      nonce
     );
+    const [priceEscrow] = await findPaymentEscrowPDA(executionReceipt);
 
-    let ix;
-    if (!reverse) {
-      ix = this.program.instruction.executeMutation({
-        accounts: {
-          transmuter: this.transmuter,
-          mutation: this.key,
-          authority,
-          owner: owner ?? this.provider.wallet.publicKey,
-          bankA,
-          vaultA,
-          bankB,
-          vaultB,
-          bankC,
-          vaultC,
-          gemBank: GEM_BANK_PROG_ID,
-          tokenAEscrow,
-          tokenATakerAta,
-          tokenAMint,
-          tokenBEscrow,
-          tokenBTakerAta,
-          tokenBMint,
-          tokenCEscrow,
-          tokenCTakerAta,
-          tokenCMint,
-          taker,
-          executionReceipt,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-          rent: SYSVAR_RENT_PUBKEY,
-        },
-      });
-    } else {
-      ix = this.program.instruction.reverseMutation({
-        accounts: {
-          transmuter: this.transmuter,
-          mutation: this.key,
-          authority,
-          owner: owner ?? this.provider.wallet.publicKey,
-          bankA,
-          vaultA,
-          bankB,
-          vaultB,
-          bankC,
-          vaultC,
-          gemBank: GEM_BANK_PROG_ID,
-          tokenAEscrow,
-          tokenATakerAta,
-          tokenAMint,
-          tokenBEscrow,
-          tokenBTakerAta,
-          tokenBMint,
-          tokenCEscrow,
-          tokenCTakerAta,
-          tokenCMint,
-          taker,
-          executionReceipt,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-          rent: SYSVAR_RENT_PUBKEY,
-        },
-      });
-    }
+    const priceMint = config.price.priceMint ?? Keypair.generate().publicKey;
+    const priceTakerAta = await getATAAddress({
+      mint: priceMint,
+      owner: taker,
+    });
+    const priceOwnerAta = await getATAAddress({ mint: priceMint, owner });
+
+    const [protocolConfig] = await findProtocolConfigPDA();
+    const { executionFeeRecipient } = await this.sdk.fetchProtocolConfig();
+    const protocolFeeRecipient = executionFeeRecipient.equals(
+      PublicKey.default
+    )
+      ? Keypair.generate().publicKey
+      : executionFeeRecipient;
+
+    // ----------------- prep nft reward
+
+    // mint is keyed by the list's minted count - if another taker's nft gets minted first, re-send
+    const nftTemplateList =
+      this._data.nftTemplateList ?? Keypair.generate().publicKey;
+    const minted = this._data.nftTemplateList
+      ? (await this.program.account.nftTemplateList.fetch(nftTemplateList))
+          .minted
+      : 0;
+    const [nftMint] = await findNftRewardMintPDA(nftTemplateList, minted);
+    const nftTakerAta = await getATAAddress({ mint: nftMint, owner: taker });
+    const [nftMetadata] = await findMetadataPDA(nftMint);
+    const [nftMasterEdition] = await findMasterEditionPDA(nftMint);
+
+    // ----------------- prep misc
 
-    const instructions = [ix];
+    const [authority] = await findTransmuterAuthorityPDA(this.transmuter);
+    const [metadataTemplate] = await findMetadataTemplatePDA(this.key);
+    const [takerRecord] = await findTakerRecordPDA(this.key, taker);
+
+    // ----------------- prep remaining accounts
+
+    // slots b, c & the extra ones, unused ones skipped:
+    // (bank, vault) per taker token, then (escrow, taker ata, mint) per maker token
+    const takerSlots: AccountMeta[] = [];
+    const takerTokens = [
+      config.takerTokenB,
+      config.takerTokenC,
+      ...config.extraTakerTokens,
+    ];
+    for (const takerToken of takerTokens) {
+      if (!takerToken) {
+        continue;
+      }
+      const { vault } = await findTakerVaultPDA(
+        takerToken.gemBank,
+        this.key,
+        taker,
+        nonce
+      );
+      takerSlots.push(meta(takerToken.gemBank, false), meta(vault, true));
+    }
+    const makerSlots: AccountMeta[] = [];
+    const makerTokens = [
+      config.makerTokenB,
+      config.makerTokenC,
+      ...config.extraMakerTokens,
+    ];
+    for (const makerToken of makerTokens) {
+      if (!makerToken) {
+        continue;
+      }
+      const [escrow, , takerAta] = await this.sdk.prepTokenAccounts(
+        this.key,
+        makerToken.mint,
+        taker
+      );
+      makerSlots.push(
+        meta(escrow, true),
+        meta(takerAta, true),
+        meta(makerToken.mint, true)
+      );
+    }
 
-    if (newMaxCompute) {
-      const extraComputeIx = this.sdk.createExtraComputeIx(newMaxCompute);
-      instructions.unshift(extraComputeIx);
+    // revenue split recipients: their wallets, then (if paid in tokens) their price ATAs
+    const recipients = (this._data.revenueSplits as any[])
+      .filter((s) => s.shareBps > 0)
+      .map((s) => s.recipient as PublicKey);
+    const splits = recipients.map((r) => meta(r, true));
+    if (config.price.priceMint) {
+      for (const recipient of recipients) {
+        const ata = await getATAAddress({ mint: priceMint, owner: recipient });
+        splits.push(meta(ata, true));
+      }
     }
 
-    return {
+    const accounts = {
+      transmuter: this.transmuter,
+      mutation: this.key,
+      owner,
       authority,
+      bankA,
+      vaultA,
+      gemBank: GEM_BANK_PROG_ID,
+      metadataTemplate,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      tokenAEscrow,
+      tokenATakerAta,
+      tokenAMint,
+      priceMint,
+      priceTakerAta,
+      priceOwnerAta,
+      priceEscrow,
+      protocolConfig,
+      protocolFeeRecipient,
+      nftTemplateList,
+      nftMint,
+      nftTakerAta,
+      nftMetadata,
+      nftMasterEdition,
+      taker,
       executionReceipt,
-      tx: new TransactionEnvelope(this.provider, instructions),
+      takerRecord,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
     };
+
+    return {
+      accounts,
+      takerSlots,
+      makerSlots,
+      splits,
+      executionReceipt,
+      config,
+    };
+  }
+
+  _needsPendingPhase(): boolean {
+    const config = this._data.config as any;
+    const lootTableEnabled = (config.lootTable.weights as number[]).some(
+      (w) => w > 0
+    );
+    return (
+      config.mutationDurationSec.gtn(0) ||
+      lootTableEnabled ||
+      !!config.nftReward.random
+    );
+  }
+
+  /**
+   * for every taker slot (a, b, c, then the extra ones) set to Burn: the vault authority, then
+   * (gem box, deposit receipt, gem mint, gem rarity, taker's gem ATA) per gem box.
+   * For every slot set to UpdateMetadata: (gem box, metadata) per gem box
+   */
+  async _prepVaultGems(taker: PublicKey, nonce: number, config: any) {
+    const vaultGems: AccountMeta[] = [];
+    const gemBumps: GemBoxBumps[] = [];
+
+    const takerTokens = [
+      config.takerTokenA,
+      config.takerTokenB,
+      config.takerTokenC,
+      ...config.extraTakerTokens,
+    ];
+    for (const takerToken of takerTokens) {
+      if (
+        !takerToken ||
+        !(takerToken.vaultAction.burn || takerToken.vaultAction.updateMetadata)
+      ) {
+        continue;
+      }
+      const bank = takerToken.gemBank;
+      const { vault } = await findTakerVaultPDA(bank, this.key, taker, nonce);
+      const gemMints = await this.fetchVaultGemMints(vault);
+
+      if (takerToken.vaultAction.burn) {
+        const [vaultAuthority] = await findVaultAuthorityPDA(vault);
+        vaultGems.push(meta(vaultAuthority, false));
+        for (const gemMint of gemMints) {
+          const [gemBox, gemBoxBump] = await findGemBoxPDA(vault, gemMint);
+          const [gdr, gdrBump] = await findGdrPDA(vault, gemMint);
+          const [gemRarity, gemRarityBump] = await findRarityPDA(
+            bank,
+            gemMint
+          );
+          const gemDestination = await getATAAddress({
+            mint: gemMint,
+            owner: taker,
+          });
+          vaultGems.push(
+            meta(gemBox, true),
+            meta(gdr, true),
+            meta(gemMint, true),
+            meta(gemRarity, false),
+            meta(gemDestination, true)
+          );
+          gemBumps.push({
+            gemBox: gemBoxBump,
+            gemDepositReceipt: gdrBump,
+            gemRarity: gemRarityBump,
+          });
+        }
+      } else {
+        for (const gemMint of gemMints) {
+          const [gemBox] = await findGemBoxPDA(vault, gemMint);
+          const [metadata] = await findMetadataPDA(gemMint);
+          vaultGems.push(meta(gemBox, false), meta(metadata, true));
+        }
+      }
+    }
+
+    return { vaultGems, gemBumps };
   }
 
-  async destroy(transmuter: PublicKey) {
+  /**
+   * mints of all the gems in a vault, one per gem box (read from its gem deposit receipts)
+   */
+  async fetchVaultGemMints(vault: PublicKey): Promise<PublicKey[]> {
+    const gdrs = await this.provider.connection.getProgramAccounts(
+      GEM_BANK_PROG_ID,
+      {
+        filters: [
+          {
+            memcmp: {
+              offset: 0,
+              bytes: utils.bytes.bs58.encode(
+                BorshAccountsCoder.accountDiscriminator("GemDepositReceipt")
+              ),
+            },
+          },
+          { memcmp: { offset: 8, bytes: vault.toBase58() } },
+        ],
+      }
+    );
+
+    // disc, vault, gem box, gem mint
+    return gdrs.map((gdr) => new PublicKey(gdr.account.data.slice(72, 104)));
+  }
+
+  /**
+   * escrowed tokens & rent go back to the transmuter owner, whoever the admin is
+   * (except for the templates' rent, which goes to the admin)
+   */
+  async destroy(transmuter: PublicKey = this.transmuter, admin?: PublicKey) {
     await this.reloadData();
     let config = this._data.config as any;
+    admin = admin ?? this.provider.wallet.publicKey;
+    const transmuterData = await this.program.account.transmuter.fetch(
+      transmuter
+    );
+    const owner = transmuterData.owner;
 
     // ----------------- prep escrows
 
     const tokenAMint = config.makerTokenA.mint;
     const [tokenAEscrow, tokenAEscrowBump, tokenADest] =
-      await this.sdk.prepTokenAccounts(this.key, tokenAMint);
+      await this.sdk.prepTokenAccounts(this.key, tokenAMint, owner);
 
     const tokenBMint = config.makerTokenB
       ? config.makerTokenB.mint
       : await createMint(this.provider);
     const [tokenBEscrow, tokenBEscrowBump, tokenBDest] =
-      await this.sdk.prepTokenAccounts(this.key, tokenBMint);
+      await this.sdk.prepTokenAccounts(this.key, tokenBMint, owner);
 
     const tokenCMint = config.makerTokenC
       ? config.makerTokenC.mint
       : await createMint(this.provider);
     const [tokenCEscrow, tokenCEscrowBump, tokenCDest] =
-      await this.sdk.prepTokenAccounts(this.key, tokenCMint);
+      await this.sdk.prepTokenAccounts(this.key, tokenCMint, owner);
+
+    // (escrow, dest, mint) for each extra maker token
+    const remainingAccounts: AccountMeta[] = [];
+    for (const makerToken of config.extraMakerTokens) {
+      if (!makerToken) {
+        continue;
+      }
+      const [escrow, , dest] = await this.sdk.prepTokenAccounts(
+        this.key,
+        makerToken.mint,
+        owner
+      );
+      remainingAccounts.push(
+        meta(escrow, true),
+        meta(dest, true),
+        meta(makerToken.mint, false)
+      );
+    }
+
+    // ----------------- prep templates
+
+    const [metadataTemplate] = await findMetadataTemplatePDA(this.key);
+    const nftTemplateList =
+      this._data.nftTemplateList ?? Keypair.generate().publicKey;
 
     // ----------------- prep ix
 
     const [authority, bump] = await findTransmuterAuthorityPDA(this.transmuter);
+    const [adminRole] = await findTransmuterRolePDA(this.transmuter, admin);
 
     const ix = this.program.instruction.destroyMutation(bump, {
       accounts: {
         transmuter,
         mutation: this.key,
-        owner: this.provider.wallet.publicKey,
+        owner,
+        admin,
+        adminRole,
         authority,
         tokenAEscrow,
         tokenADest,
@@ -229,28 +566,36 @@ export class MutationWrapper {
         tokenCEscrow,
         tokenCDest,
         tokenCMint,
+        metadataTemplate,
+        nftTemplateList,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       },
+      remainingAccounts,
     });
 
     return { authority, tx: new TransactionEnvelope(this.provider, [ix]) };
   }
 
-  async initTakerVault(bank: PublicKey, taker: PublicKey) {
+  /**
+   * a new nonce gives the taker a fresh receipt & vaults, to run the mutation again
+   */
+  async initTakerVault(bank: PublicKey, taker: PublicKey, nonce = 0) {
     const { creator, creatorBump, vault, vaultBump } = await findTakerVaultPDA(
       bank,
       this.key,
-      taker
+      taker,
+      nonce
     );
     const [executionReceipt, receiptBump] = await findExecutionReceiptPDA(
       this.key,
-      taker
+      taker,
+      nonce
     );
 
-    const ix = this.program.instruction.initTakerVault(creatorBump, {
+    const ix = this.program.instruction.initTakerVault(creatorBump, nonce, {
       accounts: {
         transmuter: this.transmuter,
         mutation: this.key,
@@ -272,6 +617,244 @@ export class MutationWrapper {
     };
   }
 
+  /**
+   * anyone can sponsor extra uses, funded from the sponsor's ATAs
+   */
+  async fund(uses: BN, sponsor?: PublicKey) {
+    await this.reloadData();
+    const config = this._data.config as any;
+    sponsor = sponsor ?? this.provider.wallet.publicKey;
+
+    const tokenAMint = config.makerTokenA.mint;
+    const [tokenAEscrow, , tokenASource] = await this.sdk.prepTokenAccounts(
+      this.key,
+      tokenAMint,
+      sponsor
+    );
+
+    const tokenBMint = config.makerTokenB
+      ? config.makerTokenB.mint
+      : tokenAMint;
+    const [tokenBEscrow, , tokenBSource] = await this.sdk.prepTokenAccounts(
+      this.key,
+      tokenBMint,
+      sponsor
+    );
+
+    const tokenCMint = config.makerTokenC
+      ? config.makerTokenC.mint
+      : tokenAMint;
+    const [tokenCEscrow, , tokenCSource] = await this.sdk.prepTokenAccounts(
+      this.key,
+      tokenCMint,
+      sponsor
+    );
+
+    // extra escrows: (escrow, source) per extra maker token
+    const remainingAccounts: AccountMeta[] = [];
+    for (const makerToken of config.extraMakerTokens) {
+      if (!makerToken) {
+        continue;
+      }
+      const [escrow, , source] = await this.sdk.prepTokenAccounts(
+        this.key,
+        makerToken.mint,
+        sponsor
+      );
+      remainingAccounts.push(meta(escrow, true), meta(source, true));
+    }
+
+    const ix = this.program.instruction.fundMutation(uses, {
+      accounts: {
+        transmuter: this.transmuter,
+        mutation: this.key,
+        tokenAEscrow,
+        tokenASource,
+        tokenBEscrow,
+        tokenBSource,
+        tokenCEscrow,
+        tokenCSource,
+        sponsor,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+      remainingAccounts,
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async update(
+    price: PriceConfig,
+    mutationDurationSec: BN,
+    name: string,
+    admin?: PublicKey
+  ) {
+    const accounts = await this._adminAccounts(admin);
+    const fullPrice = {
+      priceLamports: price.priceLamports,
+      reversalPriceLamports: price.reversalPriceLamports,
+      priceMint: price.priceMint ?? null,
+      priceAmount: price.priceAmount ?? new BN(0),
+      reversalPriceAmount: price.reversalPriceAmount ?? new BN(0),
+      pricingMode: price.pricingMode ?? { fixed: {} },
+    };
+
+    const ix = this.program.instruction.updateMutation(
+      fullPrice as any,
+      mutationDurationSec,
+      name,
+      { accounts }
+    );
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async setPaused(paused: boolean, admin?: PublicKey) {
+    const accounts = await this._adminAccounts(admin);
+    const ix = this.program.instruction.setMutationPaused(paused, {
+      accounts,
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async setTakerList(takerList: TakerList, admin?: PublicKey) {
+    const accounts = await this._adminAccounts(admin);
+    const ix = this.program.instruction.setTakerList(takerList as any, {
+      accounts,
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  /**
+   * name = null keeps each NFT's own name
+   */
+  async setMetadataTemplate(
+    name: string | null,
+    uri: string,
+    admin?: PublicKey
+  ) {
+    const accounts = await this._adminAccounts(admin);
+    const [metadataTemplate] = await findMetadataTemplatePDA(this.key);
+
+    const ix = this.program.instruction.setMetadataTemplate(name, uri, {
+      accounts: {
+        transmuter: accounts.transmuter,
+        mutation: accounts.mutation,
+        admin: accounts.admin,
+        adminRole: accounts.adminRole,
+        metadataTemplate,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return {
+      metadataTemplate,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * the list is created client side, as it can be larger than what a program can allocate
+   */
+  async initNftTemplateList(
+    symbol: string,
+    sellerFeeBasisPoints: number,
+    capacity: number,
+    admin?: PublicKey
+  ) {
+    const accounts = await this._adminAccounts(admin);
+    const nftTemplateList = Keypair.generate();
+    const space = NFT_TEMPLATE_LIST_HEADER_LEN + capacity * NFT_TEMPLATE_LEN;
+
+    const createIx = SystemProgram.createAccount({
+      fromPubkey: this.provider.wallet.publicKey,
+      newAccountPubkey: nftTemplateList.publicKey,
+      lamports:
+        await this.provider.connection.getMinimumBalanceForRentExemption(
+          space
+        ),
+      space,
+      programId: this.program.programId,
+    });
+    const ix = this.program.instruction.initNftTemplateList(
+      symbol,
+      sellerFeeBasisPoints,
+      {
+        accounts: {
+          ...accounts,
+          nftTemplateList: nftTemplateList.publicKey,
+        },
+      }
+    );
+
+    return {
+      nftTemplateList: nftTemplateList.publicKey,
+      tx: new TransactionEnvelope(
+        this.provider,
+        [createIx, ix],
+        [nftTemplateList]
+      ),
+    };
+  }
+
+  async addNftTemplates(templates: NftTemplate[], admin?: PublicKey) {
+    await this.reloadData();
+    const accounts = await this._adminAccounts(admin);
+
+    const ix = this.program.instruction.addNftTemplates(templates, {
+      accounts: {
+        transmuter: accounts.transmuter,
+        mutation: accounts.mutation,
+        admin: accounts.admin,
+        adminRole: accounts.adminRole,
+        nftTemplateList: this._data.nftTemplateList,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  /**
+   * closer has to be the taker, unless the mutation is already destroyed
+   */
+  async closeReceipt(taker: PublicKey, nonce = 0, closer?: PublicKey) {
+    const [executionReceipt] = await findExecutionReceiptPDA(
+      this.key,
+      taker,
+      nonce
+    );
+
+    const ix = this.program.instruction.closeExecutionReceipt({
+      accounts: {
+        executionReceipt,
+        mutation: this.key,
+        taker,
+        closer: closer ?? taker,
+      },
+    });
+
+    return {
+      executionReceipt,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  /**
+   * admin is the owner, or a member with the mutation manager role (defaults to the wallet)
+   */
+  async _adminAccounts(admin?: PublicKey) {
+    admin = admin ?? this.provider.wallet.publicKey;
+    const [adminRole] = await findTransmuterRolePDA(this.transmuter, admin);
+    return {
+      transmuter: this.transmuter,
+      mutation: this.key,
+      admin,
+      adminRole,
+    };
+  }
+
   // --------------------------------------- load
 
   static async load(
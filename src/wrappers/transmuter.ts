@@ -1,6 +1,6 @@
 import { TransmuterSDK } from "../sdk";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { MutationData, TransmuterData, TransmuterProgram } from "../constants";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { TransmuterData, TransmuterProgram } from "../constants";
 import {
   AugmentedProvider,
   TransactionEnvelope,
@@ -12,7 +12,7 @@ import {
   RarityConfig,
   WhitelistType,
 } from "@gemworks/gem-farm-ts";
-import { findTransmuterAuthorityPDA } from "../pda";
+import { findTransmuterAuthorityPDA, findTransmuterRolePDA } from "../pda";
 
 export class TransmuterWrapper {
   private _data?: TransmuterData;
@@ -38,13 +38,16 @@ export class TransmuterWrapper {
   /**
    * reloadData into _data
    */
-  async reloadData(): Promise<MutationData> {
+  async reloadData(): Promise<TransmuterData> {
     this._data = await this.program.account.transmuter.fetch(this.key);
     return this._data;
   }
 
   // --------------------------------------- ixs
 
+  /**
+   * proposes newOwner, who then has to accept
+   */
   async updateTransmuter(newOwner: PublicKey) {
     const ix = await this.program.instruction.updateTransmuter(newOwner, {
       accounts: {
@@ -56,12 +59,141 @@ export class TransmuterWrapper {
     return { tx: new TransactionEnvelope(this.provider, [ix]) };
   }
 
+  async acceptOwner(newOwner: PublicKey) {
+    const ix = await this.program.instruction.acceptTransmuterOwner({
+      accounts: {
+        transmuter: this.key,
+        newOwner,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async cancelOwnerTransfer() {
+    const ix = await this.program.instruction.cancelTransmuterOwner({
+      accounts: {
+        transmuter: this.key,
+        owner: this.provider.wallet.publicKey,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  /**
+   * only once all mutations are destroyed. Without newBankManager the banks stay with the authority
+   */
+  async close(newBankManager: PublicKey | null = null) {
+    await this.reloadData();
+
+    const [authority, authBump] = await findTransmuterAuthorityPDA(this.key);
+    const remainingAccounts = this._data.extraBanks
+      .filter((bank) => !bank.equals(PublicKey.default))
+      .map((bank) => ({ pubkey: bank, isWritable: true, isSigner: false }));
+
+    const ix = await this.program.instruction.closeTransmuter(
+      authBump,
+      newBankManager,
+      {
+        accounts: {
+          transmuter: this.key,
+          owner: this.provider.wallet.publicKey,
+          authority,
+          bankA: this._data.bankA,
+          bankB: this._data.bankB,
+          bankC: this._data.bankC,
+          gemBank: GEM_BANK_PROG_ID,
+        },
+        remainingAccounts,
+      }
+    );
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async addBank(payer?: PublicKey) {
+    const [authority, authBump] = await findTransmuterAuthorityPDA(this.key);
+    const bank = Keypair.generate();
+
+    const ix = await this.program.instruction.addTransmuterBank(authBump, {
+      accounts: {
+        transmuter: this.key,
+        owner: this.provider.wallet.publicKey,
+        authority,
+        bank: bank.publicKey,
+        gemBank: GEM_BANK_PROG_ID,
+        payer: payer ?? this.provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return {
+      bank: bank.publicKey,
+      tx: new TransactionEnvelope(this.provider, [ix], [bank]),
+    };
+  }
+
+  async setPaused(paused: boolean) {
+    const ix = await this.program.instruction.setTransmuterPaused(paused, {
+      accounts: {
+        transmuter: this.key,
+        owner: this.provider.wallet.publicKey,
+      },
+    });
+
+    return { tx: new TransactionEnvelope(this.provider, [ix]) };
+  }
+
+  async setRole(member: PublicKey, curator: boolean, mutationManager: boolean) {
+    const [transmuterRole] = await findTransmuterRolePDA(this.key, member);
+
+    const ix = await this.program.instruction.setTransmuterRole(
+      member,
+      curator,
+      mutationManager,
+      {
+        accounts: {
+          transmuter: this.key,
+          owner: this.provider.wallet.publicKey,
+          transmuterRole,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return {
+      transmuterRole,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
+  async revokeRole(member: PublicKey) {
+    const [transmuterRole] = await findTransmuterRolePDA(this.key, member);
+
+    const ix = await this.program.instruction.revokeTransmuterRole({
+      accounts: {
+        transmuter: this.key,
+        owner: this.provider.wallet.publicKey,
+        transmuterRole,
+      },
+    });
+
+    return {
+      transmuterRole,
+      tx: new TransactionEnvelope(this.provider, [ix]),
+    };
+  }
+
   async addToBankWhitelist(
     bank: PublicKey,
     addressToWhitelist: PublicKey,
-    whitelistType: WhitelistType
+    whitelistType: WhitelistType,
+    admin?: PublicKey
   ) {
     await this.reloadData();
+    admin = admin ?? this.provider.wallet.publicKey;
+    const [adminRole] = await findTransmuterRolePDA(this.key, admin);
 
     const [authority, authBump] = await findTransmuterAuthorityPDA(this.key);
     const [whitelistProof, wlBump] = await findWhitelistProofPDA(
@@ -75,7 +207,8 @@ export class TransmuterWrapper {
       {
         accounts: {
           transmuter: this.key,
-          owner: this.provider.wallet.publicKey,
+          admin,
+          adminRole,
           authority,
           bank,
           addressToWhitelist,
@@ -93,8 +226,14 @@ export class TransmuterWrapper {
     };
   }
 
-  async removeFromBankWhitelist(bank: PublicKey, addressToRemove: PublicKey) {
+  async removeFromBankWhitelist(
+    bank: PublicKey,
+    addressToRemove: PublicKey,
+    admin?: PublicKey
+  ) {
     await this.reloadData();
+    admin = admin ?? this.provider.wallet.publicKey;
+    const [adminRole] = await findTransmuterRolePDA(this.key, admin);
 
     const [authority, authBump] = await findTransmuterAuthorityPDA(this.key);
     const [whitelistProof, wlBump] = await findWhitelistProofPDA(
@@ -108,7 +247,9 @@ export class TransmuterWrapper {
       {
         accounts: {
           transmuter: this.key,
-          owner: this.provider.wallet.publicKey,
+          owner: this._data.owner,
+          admin,
+          adminRole,
           authority,
           bank,
           addressToRemove,
@@ -125,8 +266,14 @@ export class TransmuterWrapper {
     };
   }
 
-  async addRaritiesToBank(bank: PublicKey, rarityConfigs: RarityConfig[]) {
+  async addRaritiesToBank(
+    bank: PublicKey,
+    rarityConfigs: RarityConfig[],
+    admin?: PublicKey
+  ) {
     await this.reloadData();
+    admin = admin ?? this.provider.wallet.publicKey;
+    const [adminRole] = await findTransmuterRolePDA(this.key, admin);
 
     const [authority, authBump] = await findTransmuterAuthorityPDA(this.key);
 
@@ -156,7 +303,8 @@ export class TransmuterWrapper {
       {
         accounts: {
           transmuter: this.key,
-          owner: this.provider.wallet.publicKey,
+          admin,
+          adminRole,
           authority,
           bank,
           gemBank: GEM_BANK_PROG_ID,
@@ -0,0 +1,72 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BankSetup, findTransmuterAuthorityPDA } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (bank setup)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("skips unused banks", async () => {
+    const { transmuterWrapper, tx } = await mt.sdk.initTransmuter(undefined, {
+      bankB: { setup: BankSetup.Skip },
+      bankC: { setup: BankSetup.Skip },
+    });
+    await expectTX(tx, "init transmuter w/ 1 bank").to.be.fulfilled;
+
+    await transmuterWrapper.reloadData();
+    expect(transmuterWrapper.data.bankB).to.eqAddress(PublicKey.default);
+    expect(transmuterWrapper.data.bankC).to.eqAddress(PublicKey.default);
+
+    //single bank mutations still work
+    const mt2 = await MutationTester.load(transmuterWrapper);
+    await mt2.prepareMutation({});
+    const { tx: executeTx } = await mt2.mutation.execute(mt2.taker.publicKey);
+    executeTx.addSigners(mt2.taker);
+    await expectTX(executeTx, "executes mutation").to.be.fulfilled;
+  });
+
+  it("can't skip the first bank", async () => {
+    const { tx } = await mt.sdk.initTransmuter(undefined, {
+      bankA: { setup: BankSetup.Skip },
+    });
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.InvalidBankSetup.code.toString(16)
+    );
+  });
+
+  it("imports a bank managed by the transmuter's authority", async () => {
+    const transmuter = Keypair.generate();
+    const [authority] = await findTransmuterAuthorityPDA(transmuter.publicKey);
+
+    //existing bank, handed over to the (future) authority
+    const bank = Keypair.generate();
+    await mt.gb.initBank(bank, mt.maker, mt.maker);
+    await mt.gb.updateBankManager(bank.publicKey, mt.maker, authority);
+
+    const { transmuterWrapper, tx } = await mt.sdk.initTransmuter(undefined, {
+      bankA: { setup: BankSetup.Import, bank: bank.publicKey },
+      transmuter,
+    });
+    await expectTX(tx, "init transmuter w/ imported bank").to.be.fulfilled;
+
+    await transmuterWrapper.reloadData();
+    expect(transmuterWrapper.data.bankA).to.eqAddress(bank.publicKey);
+  });
+
+  it("refuses to import a bank it doesn't manage", async () => {
+    const bank = Keypair.generate();
+    await mt.gb.initBank(bank, mt.maker, mt.maker);
+
+    const { tx } = await mt.sdk.initTransmuter(undefined, {
+      bankA: { setup: BankSetup.Import, bank: bank.publicKey },
+    });
+    await expect(tx.confirm()).to.be.rejected;
+  });
+});
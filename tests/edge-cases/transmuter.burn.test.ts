@@ -0,0 +1,87 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { pause, toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { VaultAction } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (burn)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  const verifyGemsBurned = async () => {
+    const vaultAcc = await mt.gb.fetchVaultAcc(mt.takerVaultA);
+    expect(vaultAcc.gemCount.toNumber()).to.eq(0);
+
+    //gone for good, and the emptied ATA is closed
+    expect((await mt.conn.getTokenSupply(mt.takerMintA)).value.amount).to.eq(
+      "0"
+    );
+    expect(await mt.conn.getAccountInfo(mt.takerAccA)).to.be.null;
+  };
+
+  it("burns the taker's gems on execution", async () => {
+    await mt.prepareMutation({ vaultAction: VaultAction.Burn });
+
+    //gem accounts are looked up & passed by the sdk
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    await verifyGemsBurned();
+    await mt.verifyTakerReceivedMakerTokens();
+  });
+
+  it("only burns once a timed mutation completes", async () => {
+    await mt.prepareMutation({
+      vaultAction: VaultAction.Burn,
+      mutationDurationSec: toBN(2),
+    });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    //still there & locked while pending
+    const vaultAcc = await mt.gb.fetchVaultAcc(mt.takerVaultA);
+    expect(vaultAcc.gemCount).to.be.bignumber.eq(mt.takerTokenAmount);
+    expect(vaultAcc.locked).to.be.true;
+
+    console.log("pausing for mutation duration");
+    await pause(3000);
+
+    const { tx: completeTx } = await mt.mutation.execute(mt.taker.publicKey);
+    completeTx.addSigners(mt.taker);
+    await expectTX(completeTx, "completes mutation").to.be.fulfilled;
+
+    await verifyGemsBurned();
+  });
+
+  it("won't burn more than the required amount", async () => {
+    await mt.prepareMutation({ vaultAction: VaultAction.Burn });
+
+    //one gem on top of what's required
+    const [extraMint, extraAcc] = await mt.sdk.createMintAndATA(
+      toBN(1),
+      mt.taker
+    );
+    await mt.gb.depositGem(
+      mt.transmuter.bankA,
+      mt.takerVaultA,
+      mt.taker,
+      toBN(1),
+      extraMint,
+      extraAcc
+    );
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.ExcessVaultGems.code.toString(16)
+    );
+  });
+});
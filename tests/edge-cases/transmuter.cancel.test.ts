@@ -0,0 +1,100 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { pause, toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BPS_DENOMINATOR, ExecutionState } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (cancel)", () => {
+  let mt: MutationTester;
+
+  // matches the default price set by the tester
+  const price = 0.1 * LAMPORTS_PER_SOL;
+  const cancellationPenaltyBps = 1000;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("refunds an escrowed payment minus the penalty", async () => {
+    const makerWallet = Keypair.generate().publicKey;
+    await mt.prepareMutation({
+      mutationDurationSec: toBN(60),
+      extraConfig: { cancellationPenaltyBps },
+      revenueSplits: [{ recipient: makerWallet, shareBps: BPS_DENOMINATOR }],
+    });
+
+    const { tx, executionReceipt } = await mt.mutation.execute(
+      mt.taker.publicKey
+    );
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    //payment held on the receipt, maker not paid yet
+    const receipt = await mt.sdk.fetchReceipt(
+      mt.mutation.key,
+      mt.taker.publicKey
+    );
+    expect(receipt.state).to.deep.eq(ExecutionState.Pending);
+    expect(receipt.escrowedLamports.toNumber()).to.eq(price);
+    expect(await mt.conn.getBalance(makerWallet)).to.eq(0);
+    const receiptBalance = await mt.conn.getBalance(executionReceipt);
+
+    const takerBalance = await mt.conn.getBalance(mt.taker.publicKey);
+    const { tx: cancelTx } = await mt.mutation.cancel(mt.taker.publicKey);
+    cancelTx.addSigners(mt.taker);
+    await expectTX(cancelTx, "cancels mutation").to.be.fulfilled;
+
+    //penalty to the maker, the rest back to the taker
+    const penalty = (price * cancellationPenaltyBps) / BPS_DENOMINATOR;
+    expect(await mt.conn.getBalance(makerWallet)).to.eq(penalty);
+    expect(await mt.conn.getBalance(executionReceipt)).to.eq(
+      receiptBalance - price
+    );
+    expect(await mt.conn.getBalance(mt.taker.publicKey)).to.be.gt(
+      takerBalance
+    );
+
+    //receipt reset, use back & vault unlocked
+    const receipt2 = await mt.sdk.fetchReceipt(
+      mt.mutation.key,
+      mt.taker.publicKey
+    );
+    expect(receipt2.state).to.deep.eq(ExecutionState.NotStarted);
+    expect(receipt2.escrowedLamports.toNumber()).to.eq(0);
+
+    await mt.mutation.reloadData();
+    expect(mt.mutation.data.remainingUses.toNumber()).to.eq(1);
+
+    await mt.verifyVault(false, mt.taker);
+  });
+
+  it("can't cancel an execution that isn't pending", async () => {
+    await mt.prepareMutation({ mutationDurationSec: toBN(60) });
+
+    const { tx } = await mt.mutation.cancel(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.ExecutionNotPending.code.toString(16)
+    );
+  });
+
+  it("can't cancel once the execution is due", async () => {
+    await mt.prepareMutation({ mutationDurationSec: toBN(2) });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    console.log("pausing for mutation duration");
+    await pause(3000);
+
+    const { tx: cancelTx } = await mt.mutation.cancel(mt.taker.publicKey);
+    cancelTx.addSigners(mt.taker);
+    await expect(cancelTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.CancellationWindowClosed.code.toString(16)
+    );
+  });
+});
@@ -0,0 +1,92 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { VaultAction } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (close receipt)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("returns the receipt's rent to the taker", async () => {
+    await mt.prepareMutation({ vaultAction: VaultAction.DoNothing });
+
+    const { tx, executionReceipt } = await mt.mutation.execute(
+      mt.taker.publicKey
+    );
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    const rent = await mt.conn.getBalance(executionReceipt);
+    const takerBalance = await mt.conn.getBalance(mt.taker.publicKey);
+
+    const { tx: closeTx } = await mt.mutation.closeReceipt(mt.taker.publicKey);
+    closeTx.addSigners(mt.taker);
+    await expectTX(closeTx, "closes receipt").to.be.fulfilled;
+
+    expect(await mt.conn.getAccountInfo(executionReceipt)).to.be.null;
+    expect(await mt.conn.getBalance(mt.taker.publicKey)).to.eq(
+      takerBalance + rent
+    );
+  });
+
+  it("can't close a pending receipt", async () => {
+    await mt.prepareMutation({ mutationDurationSec: toBN(60) });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    const { tx: closeTx } = await mt.mutation.closeReceipt(mt.taker.publicKey);
+    closeTx.addSigners(mt.taker);
+    await expect(closeTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.ExecutionStillPending.code.toString(16)
+    );
+  });
+
+  it("can't close a receipt that keeps vaults locked", async () => {
+    await mt.prepareMutation({ vaultAction: VaultAction.Lock });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    const { tx: closeTx } = await mt.mutation.closeReceipt(mt.taker.publicKey);
+    closeTx.addSigners(mt.taker);
+    await expect(closeTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.ReceiptLocksVaults.code.toString(16)
+    );
+  });
+
+  it("lets anyone close receipts of destroyed mutations", async () => {
+    await mt.prepareMutation({});
+
+    const { tx, executionReceipt } = await mt.mutation.execute(
+      mt.taker.publicKey
+    );
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    //only the taker while the mutation is alive
+    const stranger = Keypair.generate();
+    const { tx: strangerTx } = await mt.mutation.closeReceipt(
+      mt.taker.publicKey,
+      0,
+      stranger.publicKey
+    );
+    strangerTx.addSigners(stranger);
+    await expect(strangerTx.confirm()).to.be.rejected;
+
+    const { tx: destroyTx } = await mt.mutation.destroy();
+    await expectTX(destroyTx, "destroys mutation").to.be.fulfilled;
+
+    await expectTX(strangerTx, "closes receipt").to.be.fulfilled;
+    expect(await mt.conn.getAccountInfo(executionReceipt)).to.be.null;
+  });
+});
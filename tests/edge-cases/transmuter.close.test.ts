@@ -0,0 +1,53 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { findTransmuterAuthorityPDA } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (close)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("only closes once all mutations are destroyed", async () => {
+    await mt.prepareMutation({});
+
+    const { tx } = await mt.transmuter.close();
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.TransmuterHasMutations.code.toString(16)
+    );
+
+    const { tx: destroyTx } = await mt.mutation.destroy();
+    await expectTX(destroyTx, "destroys mutation").to.be.fulfilled;
+
+    const { tx: closeTx } = await mt.transmuter.close();
+    await expectTX(closeTx, "closes transmuter").to.be.fulfilled;
+
+    expect(await mt.conn.getAccountInfo(mt.transmuter.key)).to.be.null;
+
+    //banks stay with the authority
+    const [authority] = await findTransmuterAuthorityPDA(mt.transmuter.key);
+    const bankAcc = await mt.gb.fetchBankAcc(mt.transmuter.bankA);
+    expect(bankAcc.bankManager).to.eqAddress(authority);
+  });
+
+  it("hands the banks over to a new manager", async () => {
+    const newBankManager = Keypair.generate().publicKey;
+
+    const { tx } = await mt.transmuter.close(newBankManager);
+    await expectTX(tx, "closes transmuter").to.be.fulfilled;
+
+    for (const bank of [
+      mt.transmuter.bankA,
+      mt.transmuter.bankB,
+      mt.transmuter.bankC,
+    ]) {
+      const bankAcc = await mt.gb.fetchBankAcc(bank);
+      expect(bankAcc.bankManager).to.eqAddress(newBankManager);
+    }
+  });
+});
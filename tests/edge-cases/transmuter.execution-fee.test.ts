@@ -0,0 +1,70 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { BPS_DENOMINATOR } from "../../src";
+
+describe("transmuter (execution fee)", () => {
+  let mt: MutationTester;
+
+  // matches the default price set by the tester
+  const price = 0.1 * LAMPORTS_PER_SOL;
+  const executionFeeBps = 500;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  afterEach("restore protocol config", async () => {
+    const { tx } = await mt.sdk.updateProtocolConfig({});
+    await tx.confirm();
+  });
+
+  // revenue goes to a fresh wallet, so the maker's cut can be measured exactly
+  const prepareFeeMutation = async () => {
+    const makerWallet = Keypair.generate().publicKey;
+    await mt.prepareMutation({
+      revenueSplits: [{ recipient: makerWallet, shareBps: BPS_DENOMINATOR }],
+    });
+    return makerWallet;
+  };
+
+  it("takes the execution fee out of the maker's payment", async () => {
+    const feeRecipient = Keypair.generate().publicKey;
+    const { tx: configTx } = await mt.sdk.updateProtocolConfig({
+      executionFeeRecipient: feeRecipient,
+      executionFeeBps,
+    });
+    await expectTX(configTx, "sets execution fee").to.be.fulfilled;
+
+    const makerWallet = await prepareFeeMutation();
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    const fee = (price * executionFeeBps) / BPS_DENOMINATOR;
+    expect(await mt.conn.getBalance(feeRecipient)).to.eq(fee);
+    expect(await mt.conn.getBalance(makerWallet)).to.eq(price - fee);
+  });
+
+  it("skips the execution fee for exempt owners", async () => {
+    const feeRecipient = Keypair.generate().publicKey;
+    const { tx: configTx } = await mt.sdk.updateProtocolConfig({
+      executionFeeRecipient: feeRecipient,
+      executionFeeBps,
+      feeExempt: [mt.maker],
+    });
+    await expectTX(configTx, "sets execution fee").to.be.fulfilled;
+
+    const makerWallet = await prepareFeeMutation();
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    expect(await mt.conn.getBalance(feeRecipient)).to.eq(0);
+    expect(await mt.conn.getBalance(makerWallet)).to.eq(price);
+  });
+});
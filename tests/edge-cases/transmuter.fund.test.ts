@@ -0,0 +1,82 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { MutationState } from "../../src";
+
+describe("transmuter (fund)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("tops up uses & escrows", async () => {
+    await mt.prepareMutation({
+      makerTokenBAmountPerUse: mt.makerTokenAmountPerUse,
+    });
+
+    //fund 2 more uses
+    await mt.mintMakerTokens(mt.makerMintA, mt.makerTokenAmountPerUse.muln(2));
+    await mt.mintMakerTokens(mt.makerMintB, mt.makerTokenAmountPerUse.muln(2));
+
+    const { tx } = await mt.mutation.fund(toBN(2));
+    await expectTX(tx, "funds mutation").to.be.fulfilled;
+
+    //verify uses
+    await mt.mutation.reloadData();
+    const data = mt.mutation.data;
+    expect(data.totalUses.toNumber()).to.eq(3);
+    expect(data.remainingUses.toNumber()).to.eq(3);
+
+    //verify funding, recorded & in escrows
+    const expectedFunding = mt.makerTokenAmountPerUse.muln(3);
+    expect(data.config.makerTokenA.totalFunding).to.be.bignumber.eq(
+      expectedFunding
+    );
+    expect(
+      (await mt.conn.getTokenAccountBalance(data.tokenAEscrow)).value.amount
+    ).to.eq(expectedFunding.toString());
+    expect(
+      (await mt.conn.getTokenAccountBalance(data.tokenBEscrow)).value.amount
+    ).to.eq(expectedFunding.toString());
+  });
+
+  it("lets anyone sponsor an exhausted mutation", async () => {
+    await mt.prepareMutation({});
+
+    //use up the only use
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    await mt.mutation.reloadData();
+    expect(mt.mutation.data.state).to.deep.eq(MutationState.Exhausted);
+
+    //sponsor (not the owner) funds 1 more use
+    const sponsor = Keypair.generate();
+    await mt.doAirdrop(sponsor.publicKey, LAMPORTS_PER_SOL);
+    await mt.mintMakerTokens(
+      mt.makerMintA,
+      mt.makerTokenAmountPerUse,
+      sponsor.publicKey
+    );
+
+    const { tx: fundTx } = await mt.mutation.fund(toBN(1), sponsor.publicKey);
+    fundTx.addSigners(sponsor);
+    await expectTX(fundTx, "sponsors mutation").to.be.fulfilled;
+
+    await mt.mutation.reloadData();
+    expect(mt.mutation.data.remainingUses.toNumber()).to.eq(1);
+    expect(mt.mutation.data.state).to.deep.eq(MutationState.Available);
+
+    //a 2nd taker can now execute
+    const taker2 = Keypair.generate();
+    await mt.prepareTakerVaults(mt.transmuter.bankA, taker2);
+    const { tx: tx2 } = await mt.mutation.execute(taker2.publicKey);
+    tx2.addSigners(taker2);
+    await expectTX(tx2, "executes mutation").to.be.fulfilled;
+  });
+});
@@ -0,0 +1,171 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { PublicKey, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+  CreateMetadataV2,
+  DataV2,
+  Metadata,
+} from "@metaplex-foundation/mpl-token-metadata";
+import { getATAAddress } from "@saberhq/token-utils";
+import {
+  findMetadataPDA,
+  findMetadataTemplatePDA,
+  findNftRewardMintPDA,
+  findTransmuterAuthorityPDA,
+  NftRewardMode,
+  VaultAction,
+} from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+// needs the token metadata program at genesis, see scripts/dump-programs.sh
+describe("transmuter (metadata)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  const fetchMetadata = async (mint: PublicKey) => {
+    const [metadata] = await findMetadataPDA(mint);
+    const { data } = await Metadata.load(mt.conn, metadata);
+    // metaplex pads stored strings with null bytes
+    return {
+      name: data.data.name.replace(/\0/g, ""),
+      uri: data.data.uri.replace(/\0/g, ""),
+    };
+  };
+
+  it("updates the metadata of the taker's gems", async () => {
+    await mt.prepareMutation({ vaultAction: VaultAction.UpdateMetadata });
+
+    //give the taker's gem metadata the transmuter can update
+    const [authority] = await findTransmuterAuthorityPDA(mt.transmuter.key);
+    const [metadata] = await findMetadataPDA(mt.takerMintA);
+    const createTx = new CreateMetadataV2(
+      { feePayer: mt.taker.publicKey },
+      {
+        metadata,
+        metadataData: new DataV2({
+          name: "gem",
+          symbol: "GEM",
+          uri: "https://example.com/gem.json",
+          sellerFeeBasisPoints: 0,
+          creators: null,
+          collection: null,
+          uses: null,
+        }),
+        updateAuthority: authority,
+        mint: mt.takerMintA,
+        mintAuthority: mt.taker.publicKey,
+      }
+    );
+    await sendAndConfirmTransaction(mt.conn, createTx, [mt.taker]);
+
+    const { tx: templateTx } = await mt.mutation.setMetadataTemplate(
+      "{name} (mutated)",
+      "https://example.com/{mint}.json"
+    );
+    await expectTX(templateTx, "sets metadata template").to.be.fulfilled;
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    expect(await fetchMetadata(mt.takerMintA)).to.deep.eq({
+      name: "gem (mutated)",
+      uri: `https://example.com/${mt.takerMintA.toBase58()}.json`,
+    });
+
+    //gems stay with the taker
+    await mt.verifyVault(false, mt.taker);
+  });
+
+  it("mints a new nft from the template list", async () => {
+    await mt.prepareMutation({
+      extraConfig: { nftReward: NftRewardMode.Sequential },
+    });
+
+    const { tx: listTx } = await mt.mutation.initNftTemplateList(
+      "MUT",
+      500,
+      2
+    );
+    await expectTX(listTx, "inits nft template list").to.be.fulfilled;
+
+    const templates = [
+      { name: "mutant #1", uri: "https://example.com/1.json" },
+      { name: "mutant #2", uri: "https://example.com/2.json" },
+    ];
+    const { tx: templatesTx } = await mt.mutation.addNftTemplates(templates);
+    await expectTX(templatesTx, "adds nft templates").to.be.fulfilled;
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    //first template minted to the taker
+    const { nftTemplateList } = await mt.mutation.reloadData();
+    const [nftMint] = await findNftRewardMintPDA(nftTemplateList, 0);
+    const nftAta = await getATAAddress({
+      mint: nftMint,
+      owner: mt.taker.publicKey,
+    });
+    expect((await mt.conn.getTokenAccountBalance(nftAta)).value.amount).to.eq(
+      "1"
+    );
+    expect(await fetchMetadata(nftMint)).to.deep.eq(templates[0]);
+
+    const program = mt.sdk.programs.Transmuter;
+    const list = await program.account.nftTemplateList.fetch(nftTemplateList);
+    expect(list.minted).to.eq(1);
+  });
+
+  it("refuses to start without an nft template left", async () => {
+    await mt.prepareMutation({
+      extraConfig: { nftReward: NftRewardMode.Sequential },
+    });
+
+    const { tx: listTx } = await mt.mutation.initNftTemplateList(
+      "MUT",
+      500,
+      1
+    );
+    await expectTX(listTx, "inits nft template list").to.be.fulfilled;
+
+    //nothing uploaded
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.NftTemplatesExhausted.code.toString(16)
+    );
+  });
+
+  it("closes the templates when the mutation is destroyed", async () => {
+    await mt.prepareMutation({
+      extraConfig: { nftReward: NftRewardMode.Sequential },
+    });
+
+    const { tx: templateTx } = await mt.mutation.setMetadataTemplate(
+      null,
+      "https://example.com/{mint}.json"
+    );
+    await expectTX(templateTx, "sets metadata template").to.be.fulfilled;
+    const { tx: listTx } = await mt.mutation.initNftTemplateList(
+      "MUT",
+      500,
+      1
+    );
+    await expectTX(listTx, "inits nft template list").to.be.fulfilled;
+
+    const [metadataTemplate] = await findMetadataTemplatePDA(mt.mutation.key);
+    const { nftTemplateList } = await mt.mutation.reloadData();
+
+    const { tx } = await mt.mutation.destroy();
+    await expectTX(tx, "destroys mutation").to.be.fulfilled;
+
+    expect(await mt.conn.getAccountInfo(metadataTemplate)).to.be.null;
+    expect(await mt.conn.getAccountInfo(nftTemplateList)).to.be.null;
+  });
+});
@@ -0,0 +1,78 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { ExecutionState } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (nonce)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("lets the same taker execute again with a new nonce", async () => {
+    await mt.prepareMutation({ uses: toBN(2) });
+
+    // ----------------- 1st run (nonce 0)
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    //same receipt can't be used twice
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.MutationAlreadyComplete.code.toString(16)
+    );
+
+    // ----------------- 2nd run (nonce 1), with fresh vaults
+    await mt.prepareTakerVaults(mt.transmuter.bankA, mt.taker, 1);
+    const { tx: tx2 } = await mt.mutation.execute(
+      mt.taker.publicKey,
+      undefined,
+      { nonce: 1 }
+    );
+    tx2.addSigners(mt.taker);
+    await expectTX(tx2, "executes mutation again").to.be.fulfilled;
+
+    //both receipts complete
+    for (const nonce of [0, 1]) {
+      const receipt = await mt.sdk.fetchReceipt(
+        mt.mutation.key,
+        mt.taker.publicKey,
+        nonce
+      );
+      expect(receipt.nonce).to.eq(nonce);
+      expect(receipt.state).to.deep.eq(ExecutionState.Complete);
+    }
+
+    //got paid out twice
+    await mt.verifyTakerReceivedMakerTokens(mt.makerTokenAmountPerUse.muln(2));
+
+    await mt.mutation.reloadData();
+    expect(mt.mutation.data.remainingUses.toNumber()).to.eq(0);
+  });
+
+  it("still enforces the per taker limit across nonces", async () => {
+    await mt.prepareMutation({
+      uses: toBN(2),
+      extraConfig: { maxExecutionsPerTaker: toBN(1) },
+    });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    await mt.prepareTakerVaults(mt.transmuter.bankA, mt.taker, 1);
+    const { tx: tx2 } = await mt.mutation.execute(
+      mt.taker.publicKey,
+      undefined,
+      { nonce: 1 }
+    );
+    tx2.addSigners(mt.taker);
+    await expect(tx2.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.TakerExecutionLimitReached.code.toString(16)
+    );
+  });
+});
@@ -0,0 +1,81 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (owner)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("only lets the proposed owner accept", async () => {
+    const newOwner = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    const { tx } = await mt.transmuter.updateTransmuter(newOwner.publicKey);
+    await expectTX(tx, "proposes owner").to.be.fulfilled;
+
+    const { tx: strangerTx } = await mt.transmuter.acceptOwner(
+      stranger.publicKey
+    );
+    strangerTx.addSigners(stranger);
+    await expect(strangerTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.NotPendingOwner.code.toString(16)
+    );
+
+    //owner unchanged
+    await mt.transmuter.reloadData();
+    expect(mt.transmuter.data.owner).to.eqAddress(mt.maker);
+  });
+
+  it("cancels a proposed transfer", async () => {
+    const newOwner = Keypair.generate();
+
+    const { tx } = await mt.transmuter.updateTransmuter(newOwner.publicKey);
+    await expectTX(tx, "proposes owner").to.be.fulfilled;
+
+    const { tx: cancelTx } = await mt.transmuter.cancelOwnerTransfer();
+    await expectTX(cancelTx, "cancels transfer").to.be.fulfilled;
+
+    await mt.transmuter.reloadData();
+    expect(mt.transmuter.data.pendingOwner).to.be.null;
+
+    //too late to accept
+    const { tx: acceptTx } = await mt.transmuter.acceptOwner(
+      newOwner.publicKey
+    );
+    acceptTx.addSigners(newOwner);
+    await expect(acceptTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.NotPendingOwner.code.toString(16)
+    );
+  });
+
+  it("takes the old owner's rights away once accepted", async () => {
+    const newOwner = Keypair.generate();
+
+    const { tx } = await mt.transmuter.updateTransmuter(newOwner.publicKey);
+    await expectTX(tx, "proposes owner").to.be.fulfilled;
+
+    //old owner keeps full rights until accepted
+    const { tx: pauseTx } = await mt.transmuter.setPaused(true);
+    await expectTX(pauseTx, "pauses transmuter").to.be.fulfilled;
+
+    const { tx: acceptTx } = await mt.transmuter.acceptOwner(
+      newOwner.publicKey
+    );
+    acceptTx.addSigners(newOwner);
+    await expectTX(acceptTx, "accepts owner").to.be.fulfilled;
+
+    await mt.transmuter.reloadData();
+    expect(mt.transmuter.data.owner).to.eqAddress(newOwner.publicKey);
+    expect(mt.transmuter.data.pendingOwner).to.be.null;
+
+    await expect(
+      mt.transmuter.setPaused(false).then(({ tx }) => tx.confirm())
+    ).to.be.rejected;
+  });
+});
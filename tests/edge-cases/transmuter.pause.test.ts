@@ -0,0 +1,97 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { pause, toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { ExecutionState } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (pause)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("pauses & unpauses a mutation", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: pauseTx } = await mt.mutation.setPaused(true);
+    await expectTX(pauseTx, "pauses mutation").to.be.fulfilled;
+
+    await mt.mutation.reloadData();
+    expect(mt.mutation.data.paused).to.be.true;
+
+    //can't execute while paused
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.MutationPaused.code.toString(16)
+    );
+
+    //can again once unpaused
+    const { tx: unpauseTx } = await mt.mutation.setPaused(false);
+    await expectTX(unpauseTx, "unpauses mutation").to.be.fulfilled;
+
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+    await mt.verifyTakerReceivedMakerTokens();
+  });
+
+  it("pauses all mutations of a transmuter", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: pauseTx } = await mt.transmuter.setPaused(true);
+    await expectTX(pauseTx, "pauses transmuter").to.be.fulfilled;
+
+    //neither new vaults nor executions
+    await expect(
+      mt.prepareTakerVaults(mt.transmuter.bankA, Keypair.generate())
+    ).to.be.rejectedWith(UtransmuterErrors.MutationPaused.code.toString(16));
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.MutationPaused.code.toString(16)
+    );
+
+    const { tx: unpauseTx } = await mt.transmuter.setPaused(false);
+    await expectTX(unpauseTx, "unpauses transmuter").to.be.fulfilled;
+
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+  });
+
+  it("lets pending executions complete while paused", async () => {
+    await mt.prepareMutation({ mutationDurationSec: toBN(2) });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    const { tx: pauseTx } = await mt.mutation.setPaused(true);
+    await expectTX(pauseTx, "pauses mutation").to.be.fulfilled;
+
+    console.log("pausing for mutation duration");
+    await pause(3000);
+
+    const { tx: completeTx } = await mt.mutation.execute(mt.taker.publicKey);
+    completeTx.addSigners(mt.taker);
+    await expectTX(completeTx, "completes mutation").to.be.fulfilled;
+
+    const receipt = await mt.sdk.fetchReceipt(
+      mt.mutation.key,
+      mt.taker.publicKey
+    );
+    expect(receipt.state).to.deep.eq(ExecutionState.Complete);
+    await mt.verifyTakerReceivedMakerTokens();
+  });
+
+  it("prevents non-admins from pausing", async () => {
+    await mt.prepareMutation({});
+
+    const stranger = Keypair.generate();
+    const { tx } = await mt.mutation.setPaused(true, stranger.publicKey);
+    tx.addSigners(stranger);
+    await expect(tx.confirm()).to.be.rejected;
+  });
+});
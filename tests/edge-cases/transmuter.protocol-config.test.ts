@@ -0,0 +1,72 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+
+describe("transmuter (protocol config)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  afterEach("restore protocol config", async () => {
+    const { tx } = await mt.sdk.updateProtocolConfig({});
+    await tx.confirm();
+  });
+
+  it("splits creation fees between the fee recipients", async () => {
+    const recipientA = Keypair.generate().publicKey;
+    const recipientB = Keypair.generate().publicKey;
+    const { tx: configTx } = await mt.sdk.updateProtocolConfig({
+      transmuterFeeLamports: toBN(LAMPORTS_PER_SOL),
+      mutationFeeLamports: toBN(0.1 * LAMPORTS_PER_SOL),
+      feeRecipients: [
+        { recipient: recipientA, shareBps: 7500 },
+        { recipient: recipientB, shareBps: 2500 },
+      ],
+    });
+    await expectTX(configTx, "sets fees").to.be.fulfilled;
+
+    await mt.prepareTransmuter();
+    expect(await mt.conn.getBalance(recipientA)).to.eq(0.75 * LAMPORTS_PER_SOL);
+    expect(await mt.conn.getBalance(recipientB)).to.eq(0.25 * LAMPORTS_PER_SOL);
+
+    await mt.prepareMutation({});
+    expect(await mt.conn.getBalance(recipientA)).to.eq(
+      0.825 * LAMPORTS_PER_SOL
+    );
+    expect(await mt.conn.getBalance(recipientB)).to.eq(
+      0.275 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it("doesn't charge fee exempt owners", async () => {
+    const recipient = Keypair.generate().publicKey;
+    const { tx: configTx } = await mt.sdk.updateProtocolConfig({
+      transmuterFeeLamports: toBN(LAMPORTS_PER_SOL),
+      feeRecipients: [{ recipient, shareBps: 10_000 }],
+      feeExempt: [mt.maker],
+    });
+    await expectTX(configTx, "sets fees").to.be.fulfilled;
+
+    await mt.prepareTransmuter();
+    expect(await mt.conn.getBalance(recipient)).to.eq(0);
+  });
+
+  it("only lets the admin update the config", async () => {
+    const stranger = Keypair.generate();
+    const { tx } = await mt.sdk.updateProtocolConfig({
+      admin: stranger.publicKey,
+      newAdmin: mt.maker,
+    });
+    tx.addSigners(stranger);
+    await expect(tx.confirm()).to.be.rejected;
+
+    //and can only be initialized once
+    const { tx: initTx } = await mt.sdk.initProtocolConfig();
+    await expect(initTx.confirm()).to.be.rejected;
+  });
+});
@@ -0,0 +1,190 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { findRarityPDA, toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  MutationConfig,
+  RequiredUnits,
+  RevenueSplit,
+  VaultAction,
+} from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (roles)", () => {
+  let mt: MutationTester;
+  let member: Keypair;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+    member = Keypair.generate();
+    await mt.doAirdrop(member.publicKey, LAMPORTS_PER_SOL);
+  });
+
+  it("lets mutation managers pause & destroy mutations", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: roleTx } = await mt.transmuter.setRole(
+      member.publicKey,
+      false,
+      true
+    );
+    await expectTX(roleTx, "grants role").to.be.fulfilled;
+
+    const role = await mt.sdk.fetchRole(mt.transmuter.key, member.publicKey);
+    expect(role.mutationManager).to.be.true;
+    expect(role.curator).to.be.false;
+
+    const { tx: pauseTx } = await mt.mutation.setPaused(true, member.publicKey);
+    pauseTx.addSigners(member);
+    await expectTX(pauseTx, "manager pauses mutation").to.be.fulfilled;
+
+    //escrowed tokens go back to the owner, not the manager
+    const data = await mt.mutation.reloadData();
+    const { tx: destroyTx } = await mt.mutation.destroy(
+      mt.transmuter.key,
+      member.publicKey
+    );
+    destroyTx.addSigners(member);
+    await expectTX(destroyTx, "manager destroys mutation").to.be.fulfilled;
+
+    expect(await mt.conn.getAccountInfo(mt.mutation.key)).to.be.null;
+    expect(await mt.conn.getAccountInfo(data.tokenAEscrow)).to.be.null;
+  });
+
+  it("keeps curators away from mutations", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: roleTx } = await mt.transmuter.setRole(
+      member.publicKey,
+      true,
+      false
+    );
+    await expectTX(roleTx, "grants role").to.be.fulfilled;
+
+    //can manage rarities
+    const mint = Keypair.generate().publicKey;
+    const { tx: rarityTx } = await mt.transmuter.addRaritiesToBank(
+      mt.transmuter.bankA,
+      [{ mint, rarityPoints: 5 }],
+      member.publicKey
+    );
+    rarityTx.addSigners(member);
+    await expectTX(rarityTx, "curator adds rarities").to.be.fulfilled;
+
+    const [rarityAddr] = await findRarityPDA(mt.transmuter.bankA, mint);
+    expect((await mt.gb.fetchRarity(rarityAddr)).points).to.eq(5);
+
+    //but not mutations
+    const { tx: pauseTx } = await mt.mutation.setPaused(true, member.publicKey);
+    pauseTx.addSigners(member);
+    await expect(pauseTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.MissingRole.code.toString(16)
+    );
+  });
+
+  it("revokes roles", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: roleTx } = await mt.transmuter.setRole(
+      member.publicKey,
+      true,
+      true
+    );
+    await expectTX(roleTx, "grants role").to.be.fulfilled;
+
+    const { tx: revokeTx, transmuterRole } = await mt.transmuter.revokeRole(
+      member.publicKey
+    );
+    await expectTX(revokeTx, "revokes role").to.be.fulfilled;
+    expect(await mt.conn.getAccountInfo(transmuterRole)).to.be.null;
+
+    const { tx: pauseTx } = await mt.mutation.setPaused(true, member.publicKey);
+    pauseTx.addSigners(member);
+    await expect(pauseTx.confirm()).to.be.rejected;
+  });
+
+  it("leaves revenue splits to the owner", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: roleTx } = await mt.transmuter.setRole(
+      member.publicKey,
+      false,
+      true
+    );
+    await expectTX(roleTx, "grants role").to.be.fulfilled;
+
+    //manager funds the mutation themselves
+    const totalFunding = toBN(10);
+    const [mint] = await mt.sdk.createMintAndATA(toBN(0));
+    await mt.mintMakerTokens(mint, totalFunding, member.publicKey);
+
+    const config: MutationConfig = {
+      takerTokenA: {
+        gemBank: mt.transmuter.bankA,
+        requiredAmount: toBN(1),
+        requiredUnits: RequiredUnits.Gems,
+        vaultAction: VaultAction.DoNothing,
+      },
+      takerTokenB: null,
+      takerTokenC: null,
+      makerTokenA: { mint, totalFunding, amountPerUse: totalFunding },
+      makerTokenB: null,
+      makerTokenC: null,
+      price: { priceLamports: toBN(0), reversalPriceLamports: toBN(0) },
+      mutationDurationSec: toBN(0),
+      reversible: false,
+    };
+    const initAsManager = async (revenueSplits: RevenueSplit[]) => {
+      const { tx } = await mt.sdk.initMutation(
+        config,
+        mt.transmuter.key,
+        toBN(1),
+        undefined,
+        "managed",
+        { admin: member.publicKey, revenueSplits }
+      );
+      tx.addSigners(member);
+      return tx;
+    };
+
+    const splitTx = await initAsManager([
+      { recipient: member.publicKey, shareBps: 10_000 },
+    ]);
+    await expect(splitTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.OwnerOnlyMutationConfig.code.toString(16)
+    );
+
+    const tx = await initAsManager([]);
+    await expectTX(tx, "manager inits mutation").to.be.fulfilled;
+  });
+
+  it("revokes roles once the transmuter changes owner", async () => {
+    await mt.prepareMutation({});
+
+    const { tx: roleTx } = await mt.transmuter.setRole(
+      member.publicKey,
+      false,
+      true
+    );
+    await expectTX(roleTx, "grants role").to.be.fulfilled;
+
+    const newOwner = Keypair.generate();
+    const { tx: proposeTx } = await mt.transmuter.updateTransmuter(
+      newOwner.publicKey
+    );
+    await expectTX(proposeTx, "proposes owner").to.be.fulfilled;
+    const { tx: acceptTx } = await mt.transmuter.acceptOwner(
+      newOwner.publicKey
+    );
+    acceptTx.addSigners(newOwner);
+    await expectTX(acceptTx, "accepts owner").to.be.fulfilled;
+
+    const { tx: pauseTx } = await mt.mutation.setPaused(true, member.publicKey);
+    pauseTx.addSigners(member);
+    await expect(pauseTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.RoleRevoked.code.toString(16)
+    );
+  });
+});
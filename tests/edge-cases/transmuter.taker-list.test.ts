@@ -0,0 +1,83 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { Keypair } from "@solana/web3.js";
+import { TakerListTree, TakerListType } from "../../src";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (taker list)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("only lets allowlisted takers execute", async () => {
+    await mt.prepareMutation({ uses: toBN(2) });
+
+    const others = [...Array(4)].map(() => Keypair.generate().publicKey);
+    const tree = TakerListTree.allowlist([...others, mt.taker.publicKey]);
+    const { tx: listTx } = await mt.mutation.setTakerList({
+      listType: TakerListType.Allowlist,
+      root: tree.root,
+    });
+    await expectTX(listTx, "sets allowlist").to.be.fulfilled;
+
+    //no proof
+    const { tx: noProofTx } = await mt.mutation.execute(mt.taker.publicKey);
+    noProofTx.addSigners(mt.taker);
+    await expect(noProofTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.TakerNotAllowed.code.toString(16)
+    );
+
+    //valid proof
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey, undefined, {
+      takerProof: tree.takerProof(mt.taker.publicKey),
+    });
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "executes mutation").to.be.fulfilled;
+
+    //someone else's proof doesn't work for a taker not on the list
+    const taker2 = Keypair.generate();
+    await mt.prepareTakerVaults(mt.transmuter.bankA, taker2);
+    const { tx: tx2 } = await mt.mutation.execute(taker2.publicKey, undefined, {
+      takerProof: tree.takerProof(mt.taker.publicKey),
+    });
+    tx2.addSigners(taker2);
+    await expect(tx2.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.TakerNotAllowed.code.toString(16)
+    );
+  });
+
+  it("blocks denylisted takers", async () => {
+    await mt.prepareMutation({ uses: toBN(2) });
+
+    const blocked = [...Array(3)].map(() => Keypair.generate().publicKey);
+    const tree = TakerListTree.denylist([...blocked, mt.taker.publicKey]);
+    const { tx: listTx } = await mt.mutation.setTakerList({
+      listType: TakerListType.Denylist,
+      root: tree.root,
+    });
+    await expectTX(listTx, "sets denylist").to.be.fulfilled;
+
+    //a taker outside the list proves the gap they sit in
+    const taker2 = Keypair.generate();
+    await mt.prepareTakerVaults(mt.transmuter.bankA, taker2);
+    const { tx: tx2 } = await mt.mutation.execute(taker2.publicKey, undefined, {
+      takerProof: tree.takerProof(taker2.publicKey),
+    });
+    tx2.addSigners(taker2);
+    await expectTX(tx2, "executes mutation").to.be.fulfilled;
+
+    //a blocked taker has no gap to sit in - borrowing a neighbouring one fails
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey, undefined, {
+      takerProof: tree.takerProof(taker2.publicKey),
+    });
+    tx.addSigners(mt.taker);
+    await expect(tx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.TakerNotAllowed.code.toString(16)
+    );
+  });
+});
@@ -0,0 +1,51 @@
+import "chai-bn";
+import { MutationTester } from "../mutation.tester";
+import { toBN } from "@gemworks/gem-farm-ts";
+import { expectTX } from "@saberhq/chai-solana";
+import { expect } from "chai";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { UtransmuterErrors } from "../../src/idls/transmuter";
+
+describe("transmuter (update)", () => {
+  let mt: MutationTester;
+
+  beforeEach("setup tester class", async () => {
+    mt = await MutationTester.load();
+  });
+
+  it("keeps the price mint while executions are pending", async () => {
+    await mt.prepareMutation({ mutationDurationSec: toBN(60) });
+
+    const { tx } = await mt.mutation.execute(mt.taker.publicKey);
+    tx.addSigners(mt.taker);
+    await expectTX(tx, "starts mutation").to.be.fulfilled;
+
+    const [priceMint] = await mt.sdk.createMintAndATA(toBN(0));
+    const { tx: mintTx } = await mt.mutation.update(
+      {
+        priceLamports: toBN(0),
+        reversalPriceLamports: toBN(0),
+        priceMint,
+        priceAmount: toBN(10),
+      },
+      toBN(60),
+      "mutation123"
+    );
+    await expect(mintTx.confirm()).to.be.rejectedWith(
+      UtransmuterErrors.ImmutableMutationConfig.code.toString(16)
+    );
+
+    //lamport price can still change
+    const priceLamports = toBN(0.2 * LAMPORTS_PER_SOL);
+    const { tx: priceTx } = await mt.mutation.update(
+      { priceLamports, reversalPriceLamports: toBN(0) },
+      toBN(60),
+      "mutation123"
+    );
+    await expectTX(priceTx, "updates price").to.be.fulfilled;
+
+    const data = await mt.mutation.reloadData();
+    expect(data.config.price.priceLamports).to.be.bignumber.eq(priceLamports);
+    expect(data.config.price.priceMint).to.be.null;
+  });
+});
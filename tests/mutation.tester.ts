@@ -2,6 +2,7 @@ import {
   MutationConfig,
   MutationWrapper,
   RequiredUnits,
+  RevenueSplit,
   TakerTokenConfig,
   TransmuterWrapper,
   VaultAction,
@@ -16,8 +17,15 @@ import {
   toBN,
 } from "@gemworks/gem-farm-ts";
 import { BN } from "@project-serum/anchor";
-import { makeSDK } from "./workspace";
-import { getATAAddress } from "@saberhq/token-utils";
+import { ensureProtocolConfig, makeSDK } from "./workspace";
+import {
+  getATAAddress,
+  getOrCreateATA,
+  Token,
+  TokenAmount,
+  TokenOwner,
+} from "@saberhq/token-utils";
+import { TransactionEnvelope } from "@saberhq/solana-contrib";
 import { expect } from "chai";
 
 const bankIdl = require("./programs/gem_bank.json");
@@ -94,6 +102,8 @@ export class MutationTester {
     mutationInitError = undefined,
     reversalPriceLamports = toBN(0.1 * LAMPORTS_PER_SOL),
     name = "mutation123",
+    extraConfig = {},
+    revenueSplits = [],
   }: {
     vaultAction?: any;
     mutationDurationSec?: BN;
@@ -106,6 +116,8 @@ export class MutationTester {
     mutationInitError?: string;
     reversalPriceLamports?: BN;
     name?: string;
+    extraConfig?: Partial<MutationConfig>; //for any of the optional config fields
+    revenueSplits?: RevenueSplit[];
   }) => {
     // record uses
     this.uses = uses;
@@ -157,6 +169,7 @@ export class MutationTester {
       },
       mutationDurationSec,
       reversible,
+      ...extraConfig,
     };
 
     const { mutationWrapper, tx } = await this.sdk.initMutation(
@@ -164,7 +177,8 @@ export class MutationTester {
       this.transmuter.key,
       uses,
       undefined,
-      name
+      name,
+      { revenueSplits }
     );
 
     if (!mutationInitError) {
@@ -197,6 +211,24 @@ export class MutationTester {
     }
   };
 
+  // maker is the mint authority of all maker mints
+  mintMakerTokens = async (mint: PublicKey, amount: BN, owner = this.maker) => {
+    const { address: ata, instruction } = await getOrCreateATA({
+      provider: this.sdk.provider,
+      mint,
+      owner,
+    });
+    const mintToIx = new TokenOwner(this.maker).mintTo(
+      new TokenAmount(Token.fromMint(mint, 0), amount),
+      ata
+    );
+
+    const instructions = instruction ? [instruction, mintToIx] : [mintToIx];
+    await new TransactionEnvelope(this.sdk.provider, instructions).confirm();
+
+    return ata;
+  };
+
   doAirdrop = async (receiver: PublicKey, amount: number) => {
     await this.conn
       .requestAirdrop(receiver, amount)
@@ -204,7 +236,11 @@ export class MutationTester {
       .catch((e) => console.log("failed to get airdrop", e));
   };
 
-  prepareTakerVaults = async (bank: PublicKey, taker = this.taker) => {
+  prepareTakerVaults = async (
+    bank: PublicKey,
+    taker = this.taker,
+    nonce = 0
+  ) => {
     // fund taker
     const balance = await this.conn.getBalance(taker.publicKey);
     if (balance < 0.1 * LAMPORTS_PER_SOL) {
@@ -214,7 +250,8 @@ export class MutationTester {
     // create vaults
     const { tx, vault } = await this.mutation.initTakerVault(
       bank,
-      taker.publicKey
+      taker.publicKey,
+      nonce
     );
     tx.addSigners(taker);
     await tx.confirm();
@@ -246,6 +283,7 @@ export class MutationTester {
     transmuter?: TransmuterWrapper
   ): Promise<MutationTester> => {
     let tester = new MutationTester();
+    await ensureProtocolConfig(tester.sdk);
 
     //either attach an existing transmuter (useful for multi-mutation testing), or create new
     if (transmuter) {
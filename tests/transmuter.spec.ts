@@ -1,14 +1,9 @@
-import {
-  ExecutionState,
-  feeAccount2,
-  RequiredUnits,
-  VaultAction,
-} from "../src";
+import { ExecutionState, RequiredUnits, VaultAction } from "../src";
 import { expectTX } from "@saberhq/chai-solana";
 
 import "chai-bn";
 import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { feeAccount, pause, stringToBytes, toBN } from "@gemworks/gem-farm-ts/";
+import { pause, stringToBytes, toBN } from "@gemworks/gem-farm-ts/";
 import { expect } from "chai";
 import { MutationTester } from "./mutation.tester";
 import { UtransmuterErrors } from "../src/idls/transmuter";
@@ -21,12 +16,6 @@ describe("transmuter (main spec)", () => {
   });
 
   it("execute mutation (lock vault)", async () => {
-    //verify fees collected (can't test exact amount since test order non-deterministic)
-    const feeAcc1 = await mt.conn.getBalance(feeAccount);
-    const feeAcc2 = await mt.conn.getBalance(feeAccount2);
-    expect(feeAcc1 > 0);
-    expect(feeAcc2 > 0);
-
     await mt.prepareMutation({});
 
     await mt.mutation.reloadData();
@@ -404,12 +393,22 @@ describe("transmuter (main spec)", () => {
   });
 
   it("updates transmuter owner", async () => {
-    const newOwner = Keypair.generate().publicKey;
+    const newOwner = Keypair.generate();
+
+    const { tx } = await mt.transmuter.updateTransmuter(newOwner.publicKey);
+    await expectTX(tx, "proposes owner").to.be.fulfilled;
 
-    const { tx } = await mt.transmuter.updateTransmuter(newOwner);
-    await expectTX(tx, "updates owner").to.be.fulfilled;
+    //only proposed so far
+    await mt.transmuter.reloadData();
+    expect(mt.transmuter.data.pendingOwner).to.eqAddress(newOwner.publicKey);
+
+    const { tx: acceptTx } = await mt.transmuter.acceptOwner(
+      newOwner.publicKey
+    );
+    acceptTx.addSigners(newOwner);
+    await expectTX(acceptTx, "accepts owner").to.be.fulfilled;
 
     await mt.transmuter.reloadData();
-    expect((<any>mt.transmuter.data).owner).to.eqAddress(newOwner);
+    expect(mt.transmuter.data.owner).to.eqAddress(newOwner.publicKey);
   });
 });
@@ -6,7 +6,7 @@ import { chaiSolana } from "@saberhq/chai-solana";
 import { SolanaProvider } from "@saberhq/solana-contrib";
 import chai from "chai";
 
-import { TransmuterSDK } from "../src";
+import { findProtocolConfigPDA, TransmuterSDK } from "../src";
 
 chai.use(chaiSolana);
 
@@ -22,3 +22,17 @@ export const makeSDK = (): TransmuterSDK => {
 
   return TransmuterSDK.load({ provider });
 };
+
+/**
+ * the protocol config has to exist before any transmuter can be created.
+ * Under `anchor test` the wallet is the program's upgrade authority, so it can init it
+ */
+export const ensureProtocolConfig = async (sdk: TransmuterSDK) => {
+  const [protocolConfig] = await findProtocolConfigPDA();
+  if (await sdk.provider.connection.getAccountInfo(protocolConfig)) {
+    return;
+  }
+
+  const { tx } = await sdk.initProtocolConfig();
+  await tx.confirm();
+};